@@ -0,0 +1,180 @@
+/// Bookkeeping for multiple addresses derived from one mnemonic.
+///
+/// An [`Account`] never stores key material, only enough to show it in a
+/// list and re-derive the signer on demand: its BIP-44 account index, an
+/// optional human label, and the address that index derives to.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use iota_sdk::types::Address;
+
+use crate::lock::{self, DEFAULT_LOCK_TIMEOUT};
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Account {
+    pub index: u32,
+    pub label: Option<String>,
+    pub address: Address,
+}
+
+impl Account {
+    pub fn new(index: u32, label: Option<String>, address: Address) -> Self {
+        Self {
+            index,
+            label,
+            address,
+        }
+    }
+
+    /// Whether `needle` identifies this account, either by its label
+    /// (case-insensitive) or by its account index.
+    pub fn matches(&self, needle: &str) -> bool {
+        if let Ok(index) = needle.parse::<u32>() {
+            if index == self.index {
+                return true;
+            }
+        }
+        self.label
+            .as_deref()
+            .is_some_and(|label| label.eq_ignore_ascii_case(needle))
+    }
+
+    /// `label (#index)` if labeled, else just `#index`.
+    pub fn display_name(&self) -> String {
+        match &self.label {
+            Some(label) => format!("{label} (#{})", self.index),
+            None => format!("#{}", self.index),
+        }
+    }
+}
+
+/// On-disk record of every account derived from the wallet's mnemonic so
+/// far: their BIP-44 indices, labels, and addresses. Never the private keys
+/// themselves, so this survives restarts without requiring the wallet to be
+/// unlocked.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct AccountBook {
+    accounts: Vec<Account>,
+}
+
+impl AccountBook {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the account book from `path`, returning an empty book if the file doesn't exist yet.
+    ///
+    /// Holds a shared lock on `path` for the duration of the read, so a
+    /// concurrent wallet process can't be caught mid-write by
+    /// [`Self::save`]; see [`crate::lock`].
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::new());
+        }
+        lock::with_shared(path, DEFAULT_LOCK_TIMEOUT, || {
+            let data = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read accounts file: {}", path.display()))?;
+            serde_json::from_str(&data)
+                .with_context(|| format!("Failed to parse accounts file: {}", path.display()))
+        })
+    }
+
+    /// Persist the account book to `path`, creating parent directories if needed.
+    ///
+    /// Holds an exclusive lock on `path` for the duration of the write, so
+    /// two concurrent wallet processes can't interleave writes and
+    /// corrupt the file; see [`crate::lock`].
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create accounts directory: {}", parent.display()))?;
+        }
+        let data = serde_json::to_string_pretty(self)?;
+        lock::with_exclusive(path, DEFAULT_LOCK_TIMEOUT, || {
+            fs::write(path, &data)
+                .with_context(|| format!("Failed to write accounts file: {}", path.display()))
+        })
+    }
+
+    /// All known accounts, in derivation order.
+    #[must_use]
+    pub fn accounts(&self) -> &[Account] {
+        &self.accounts
+    }
+
+    /// Append a newly derived account.
+    pub fn push(&mut self, account: Account) {
+        self.accounts.push(account);
+    }
+
+    /// The default accounts file path alongside a named wallet's data directory.
+    #[must_use]
+    pub fn default_path(wallet_dir: &Path) -> PathBuf {
+        wallet_dir.join("accounts.json")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr() -> Address {
+        Address::from_hex("0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900")
+            .unwrap()
+    }
+
+    #[test]
+    fn matches_by_index() {
+        let account = Account::new(2, None, addr());
+        assert!(account.matches("2"));
+        assert!(!account.matches("3"));
+    }
+
+    #[test]
+    fn matches_by_label_case_insensitive() {
+        let account = Account::new(1, Some("Savings".to_string()), addr());
+        assert!(account.matches("savings"));
+        assert!(account.matches("SAVINGS"));
+        assert!(!account.matches("checking"));
+    }
+
+    #[test]
+    fn display_name_with_and_without_label() {
+        assert_eq!(
+            Account::new(0, Some("Main".to_string()), addr()).display_name(),
+            "Main (#0)"
+        );
+        assert_eq!(Account::new(1, None, addr()).display_name(), "#1");
+    }
+
+    #[test]
+    fn push_and_list() {
+        let mut book = AccountBook::new();
+        book.push(Account::new(0, None, addr()));
+        book.push(Account::new(1, Some("Savings".to_string()), addr()));
+        assert_eq!(book.accounts().len(), 2);
+    }
+
+    #[test]
+    fn load_missing_file_is_empty() {
+        let book = AccountBook::load(Path::new("/nonexistent/accounts.json")).unwrap();
+        assert!(book.accounts().is_empty());
+    }
+
+    #[test]
+    fn save_and_load_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("iota-wallet-accounts-test-{}", std::process::id()));
+        let path = AccountBook::default_path(&dir);
+
+        let mut book = AccountBook::new();
+        book.push(Account::new(0, Some("Main".to_string()), addr()));
+        book.save(&path).unwrap();
+
+        let loaded = AccountBook::load(&path).unwrap();
+        assert_eq!(loaded.accounts(), book.accounts());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
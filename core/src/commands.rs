@@ -1,9 +1,14 @@
 /// Command definitions and parsing for the wallet REPL and one-shot mode.
+use std::time::Duration;
+
 use anyhow::{Result, bail};
-use iota_sdk::types::Address;
+use iota_sdk::types::{Address, Digest, ObjectId};
+use tokio::time::{sleep, Instant};
 
 use crate::display;
-use crate::network::{NetworkClient, TransactionFilter};
+use crate::display::IotaAmount;
+use crate::io::WalletIo;
+use crate::network::{NetworkClient, StakedIotaSummary, TransactionDetailsSummary, TransactionFilter};
 use crate::wallet::Wallet;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -13,19 +18,86 @@ pub enum Command {
     /// Show wallet address
     Address,
     /// Transfer IOTA to another address: transfer <address> <amount>
-    Transfer { recipient: Address, amount: u64 },
+    Transfer {
+        recipient: Address,
+        amount: IotaAmount,
+    },
+    /// Send IOTA to multiple recipients in a single transaction, cheaper in
+    /// gas than one transfer per recipient:
+    /// batch <address>:<amount> [<address>:<amount> ...]
+    Batch { payments: Vec<(Address, IotaAmount)> },
     /// Show transaction history: show_transfers [in|out|all]
     ShowTransfers { filter: TransactionFilter },
+    /// List held coin types, their symbol (if known), and balance formatted
+    /// using each coin's own decimals
+    Tokens,
+    /// Transfer a non-IOTA coin type to another address: transfer-token
+    /// <coin_type> <address> <amount>
+    /// `amount` is parsed against the coin type's own decimals, looked up
+    /// from the node at execution time, not IOTA's 9.
+    /// Alias: send-token
+    TransferToken {
+        coin_type: String,
+        recipient: Address,
+        amount: String,
+    },
+    /// Poll a transaction until it reaches a terminal state: confirm <digest>
+    /// Aliases: status, verify
+    Confirm { digest: Digest },
     /// Request faucet tokens (testnet/devnet only)
     Faucet,
     /// Show seed phrase (mnemonic)
     Seed,
+    /// Password-encrypt the wallet's mnemonic and private key at rest:
+    /// encrypt [password]. Prompted without echo if omitted.
+    Encrypt { password: Option<String> },
+    /// Decrypt into memory for `ttl_minutes` (default
+    /// [`DEFAULT_UNLOCK_TTL_MINUTES`]), after which the wallet auto-relocks:
+    /// unlock [password] [ttl_minutes]. Password prompted without echo if omitted.
+    Unlock {
+        password: Option<String>,
+        ttl_minutes: u64,
+    },
+    /// Permanently remove at-rest encryption: decrypt [password]. Prompted
+    /// without echo if omitted.
+    Decrypt { password: Option<String> },
+    /// Stake IOTA to a validator: stake <validator> <amount>
+    Stake {
+        validator: Address,
+        amount: IotaAmount,
+    },
+    /// Withdraw a staked object: unstake <staked_object_id>
+    Unstake { staked_object_id: ObjectId },
+    /// List staked objects and their rewards
+    Stakes,
+    /// Send the entire balance (minus gas) to another address: sweep <address>
+    Sweep { recipient: Address },
+    /// Derive and add a new account from the wallet's mnemonic:
+    /// account new [label]
+    AccountNew { label: Option<String> },
+    /// List known accounts with their index, label, address and balance:
+    /// account list
+    AccountList,
+    /// Switch which account subsequent commands operate on, by label or
+    /// index: account use <label_or_index>
+    AccountUse { label_or_index: String },
+    /// Start background polling of balance and transaction history:
+    /// sync on [interval_secs] (default [`DEFAULT_SYNC_INTERVAL_SECS`])
+    SyncOn { interval_secs: Option<u64> },
+    /// Stop background polling started by `sync on`
+    SyncOff,
     /// Print help
     Help { command: Option<String> },
     /// Exit the wallet
     Exit,
 }
 
+/// Default session length an `unlock` grants before the wallet auto-relocks.
+pub const DEFAULT_UNLOCK_TTL_MINUTES: u64 = 15;
+
+/// Default polling interval for `sync on` when no interval is given.
+pub const DEFAULT_SYNC_INTERVAL_SECS: u64 = 30;
+
 impl Command {
     /// Parse a command from a raw input string.
     pub fn parse(input: &str) -> Result<Self> {
@@ -64,22 +136,234 @@ impl Command {
                     anyhow::anyhow!("Invalid amount '{amount_str}': {e}")
                 })?;
 
-                if amount == 0 {
+                if amount.as_nanos() == 0 {
                     bail!("Cannot send 0 IOTA.");
                 }
 
                 Ok(Command::Transfer { recipient, amount })
             }
 
+            "batch" | "send-batch" => {
+                let rest = input.splitn(2, char::is_whitespace).nth(1).unwrap_or("").trim();
+                if rest.is_empty() {
+                    bail!(
+                        "Missing payments. Usage: batch <address>:<amount> [<address>:<amount> ...]"
+                    );
+                }
+
+                let mut payments = Vec::new();
+                for token in rest.split_whitespace() {
+                    let (addr_str, amount_str) = token.split_once(':').ok_or_else(|| {
+                        anyhow::anyhow!("Invalid payment '{token}'. Expected <address>:<amount>.")
+                    })?;
+
+                    let recipient = Address::from_hex(addr_str).map_err(|e| {
+                        anyhow::anyhow!("Invalid recipient address '{addr_str}': {e}")
+                    })?;
+                    let amount = display::parse_iota_amount(amount_str).map_err(|e| {
+                        anyhow::anyhow!("Invalid amount '{amount_str}': {e}")
+                    })?;
+
+                    if amount.as_nanos() == 0 {
+                        bail!("Cannot send 0 IOTA to {addr_str}.");
+                    }
+
+                    payments.push((recipient, amount));
+                }
+
+                Ok(Command::Batch { payments })
+            }
+
             "show_transfers" | "transfers" | "txs" => {
                 let filter = TransactionFilter::from_str_opt(arg1);
                 Ok(Command::ShowTransfers { filter })
             }
 
+            "tokens" => Ok(Command::Tokens),
+
+            "transfer-token" | "send-token" => {
+                let coin_type = arg1.ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Missing coin type. Usage: transfer-token <coin_type> <address> <amount>"
+                    )
+                })?;
+                let rest = arg2.ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Missing recipient and amount. Usage: transfer-token <coin_type> <address> <amount>"
+                    )
+                })?;
+
+                let mut rest_parts = rest.splitn(2, char::is_whitespace);
+                let addr_str = rest_parts.next().unwrap().trim();
+                let amount_str = rest_parts
+                    .next()
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Missing amount. Usage: transfer-token <coin_type> <address> <amount>"
+                        )
+                    })?;
+
+                let recipient = Address::from_hex(addr_str).map_err(|e| {
+                    anyhow::anyhow!("Invalid recipient address '{addr_str}': {e}")
+                })?;
+
+                Ok(Command::TransferToken {
+                    coin_type: coin_type.to_string(),
+                    recipient,
+                    amount: amount_str.to_string(),
+                })
+            }
+
+            "confirm" | "status" | "verify" => {
+                let digest_str = arg1.ok_or_else(|| {
+                    anyhow::anyhow!("Missing digest. Usage: confirm <digest>")
+                })?;
+                let digest = digest_str
+                    .parse::<Digest>()
+                    .map_err(|e| anyhow::anyhow!("Invalid digest '{digest_str}': {e}"))?;
+                Ok(Command::Confirm { digest })
+            }
+
             "faucet" => Ok(Command::Faucet),
 
             "seed" => Ok(Command::Seed),
 
+            "encrypt" => {
+                if let Some(password) = arg1 {
+                    crate::vault::require_nonempty_password(password)?;
+                }
+                Ok(Command::Encrypt {
+                    password: arg1.map(str::to_string),
+                })
+            }
+
+            "unlock" => {
+                if let Some(password) = arg1 {
+                    crate::vault::require_nonempty_password(password)?;
+                }
+                let ttl_minutes = arg2
+                    .map(|s| {
+                        s.parse::<u64>()
+                            .map_err(|_| anyhow::anyhow!("Invalid TTL '{s}': expected minutes"))
+                    })
+                    .transpose()?
+                    .unwrap_or(DEFAULT_UNLOCK_TTL_MINUTES);
+                Ok(Command::Unlock {
+                    password: arg1.map(str::to_string),
+                    ttl_minutes,
+                })
+            }
+
+            "decrypt" => {
+                if let Some(password) = arg1 {
+                    crate::vault::require_nonempty_password(password)?;
+                }
+                Ok(Command::Decrypt {
+                    password: arg1.map(str::to_string),
+                })
+            }
+
+            "stake" => {
+                let validator_str = arg1.ok_or_else(|| {
+                    anyhow::anyhow!("Missing validator address. Usage: stake <validator> <amount>")
+                })?;
+                let amount_str = arg2.ok_or_else(|| {
+                    anyhow::anyhow!("Missing amount. Usage: stake <validator> <amount>")
+                })?;
+
+                let validator = Address::from_hex(validator_str).map_err(|e| {
+                    anyhow::anyhow!("Invalid validator address '{validator_str}': {e}")
+                })?;
+                let amount = display::parse_iota_amount(amount_str)
+                    .map_err(|e| anyhow::anyhow!("Invalid amount '{amount_str}': {e}"))?;
+
+                if amount.as_nanos() == 0 {
+                    bail!("Cannot stake 0 IOTA.");
+                }
+
+                Ok(Command::Stake { validator, amount })
+            }
+
+            "unstake" => {
+                let object_str = arg1.ok_or_else(|| {
+                    anyhow::anyhow!("Missing staked object id. Usage: unstake <staked_object_id>")
+                })?;
+                let staked_object_id = ObjectId::from_hex(object_str).map_err(|e| {
+                    anyhow::anyhow!("Invalid staked object id '{object_str}': {e}")
+                })?;
+                Ok(Command::Unstake { staked_object_id })
+            }
+
+            "stakes" => Ok(Command::Stakes),
+
+            "sweep" => {
+                let recipient_str = arg1.ok_or_else(|| {
+                    anyhow::anyhow!("Missing recipient address. Usage: sweep <address>")
+                })?;
+                let recipient = Address::from_hex(recipient_str).map_err(|e| {
+                    anyhow::anyhow!("Invalid recipient address '{recipient_str}': {e}")
+                })?;
+                Ok(Command::Sweep { recipient })
+            }
+
+            "account" => {
+                let sub = arg1.ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Missing subcommand. Usage: account new [label] | account list | account use <label_or_index>"
+                    )
+                })?;
+
+                match sub.to_lowercase().as_str() {
+                    "new" => Ok(Command::AccountNew {
+                        label: arg2.map(|s| s.to_string()),
+                    }),
+
+                    "list" | "ls" => Ok(Command::AccountList),
+
+                    "use" => {
+                        let label_or_index = arg2.ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "Missing label or index. Usage: account use <label_or_index>"
+                            )
+                        })?;
+                        Ok(Command::AccountUse {
+                            label_or_index: label_or_index.to_string(),
+                        })
+                    }
+
+                    other => bail!(
+                        "Unknown account subcommand: '{other}'. Usage: account new [label] | \
+                         account list | account use <label_or_index>"
+                    ),
+                }
+            }
+
+            "sync" => {
+                let sub = arg1.ok_or_else(|| {
+                    anyhow::anyhow!("Missing subcommand. Usage: sync on [interval_secs] | sync off")
+                })?;
+
+                match sub.to_lowercase().as_str() {
+                    "on" => {
+                        let interval_secs = match arg2 {
+                            Some(s) => Some(s.parse::<u64>().map_err(|_| {
+                                anyhow::anyhow!("Invalid interval '{s}'. Usage: sync on [interval_secs]")
+                            })?),
+                            None => None,
+                        };
+                        Ok(Command::SyncOn { interval_secs })
+                    }
+
+                    "off" => Ok(Command::SyncOff),
+
+                    other => bail!(
+                        "Unknown sync subcommand: '{other}'. Usage: sync on [interval_secs] | sync off"
+                    ),
+                }
+            }
+
             "help" | "?" => Ok(Command::Help {
                 command: arg1.map(|s| s.to_string()),
             }),
@@ -94,16 +378,79 @@ impl Command {
 
     /// Whether this command should prompt for confirmation before executing.
     pub fn requires_confirmation(&self) -> bool {
-        matches!(self, Command::Seed)
+        matches!(
+            self,
+            Command::Seed
+                | Command::Decrypt { .. }
+                | Command::Stake { .. }
+                | Command::Unstake { .. }
+                | Command::Sweep { .. }
+        )
+    }
+
+    /// Whether this command needs the decrypted mnemonic/private key in
+    /// memory, and so must fail while the wallet is locked.
+    pub fn requires_unlocked(&self) -> bool {
+        matches!(
+            self,
+            Command::Transfer { .. }
+                | Command::Batch { .. }
+                | Command::TransferToken { .. }
+                | Command::Faucet
+                | Command::Seed
+                | Command::Stake { .. }
+                | Command::Unstake { .. }
+                | Command::Sweep { .. }
+                | Command::AccountNew { .. }
+        )
+    }
+
+    /// What confirming this command will do, shown as the prompt in
+    /// [`Self::execute`] for commands where [`Self::requires_confirmation`]
+    /// is true.
+    fn confirmation_prompt(&self) -> String {
+        match self {
+            Command::Seed => "This will display your seed phrase in plain text.".to_string(),
+            Command::Decrypt { .. } => {
+                "This will permanently remove at-rest encryption.".to_string()
+            }
+            Command::Stake { validator, amount } => {
+                format!("This will stake {amount} to {validator}.")
+            }
+            Command::Unstake { staked_object_id } => {
+                format!("This will withdraw stake {staked_object_id}.")
+            }
+            Command::Sweep { recipient } => {
+                format!("This will send your entire balance (minus gas) to {recipient}.")
+            }
+            _ => "This action requires confirmation.".to_string(),
+        }
     }
 
     /// Execute a command and return the output string.
+    ///
+    /// Prompts for confirmation through `io` for commands where
+    /// [`Self::requires_confirmation`] is true, instead of hard-coding
+    /// stdin/stdout — pass a [`crate::io::TerminalIo`] for an interactive
+    /// CLI or a [`crate::io::ScriptedIo`] to drive this non-interactively.
     pub async fn execute(
         &self,
         wallet: &Wallet,
         network: &NetworkClient,
         json_output: bool,
+        io: &mut dyn WalletIo,
     ) -> Result<String> {
+        if self.requires_unlocked() && wallet.is_locked() {
+            bail!("Wallet is locked. Run 'unlock <password>' to sign or reveal the seed.");
+        }
+
+        if self.requires_confirmation() {
+            let answer = io.prompt(&format!("{} Continue? [y/N] ", self.confirmation_prompt()));
+            if !matches!(answer.trim().to_lowercase().as_str(), "y" | "yes") {
+                bail!("Cancelled.");
+            }
+        }
+
         match self {
             Command::Balance => {
                 let nanos = network.balance(wallet.address()).await?;
@@ -124,12 +471,24 @@ impl Command {
             }
 
             Command::Transfer { recipient, amount } => {
+                let preflight = network
+                    .estimate_transfer(wallet.address(), *recipient, amount.as_nanos())
+                    .await?;
+                if let Some(shortfall) = preflight.shortfall {
+                    bail!(
+                        "Insufficient funds: need {shortfall} more IOTA to cover amount + gas \
+                         (requires {}, gas budget {}).",
+                        preflight.total_required,
+                        preflight.gas_budget,
+                    );
+                }
+
                 let result = network
                     .send_iota(
                         wallet.private_key(),
                         wallet.address(),
                         *recipient,
-                        *amount,
+                        amount.as_nanos(),
                     )
                     .await?;
 
@@ -137,18 +496,54 @@ impl Command {
                     Ok(serde_json::json!({
                         "digest": result.digest,
                         "status": result.status,
-                        "amount_nanos": amount,
-                        "amount_iota": display::nanos_to_iota(*amount),
+                        "amount": amount,
                         "recipient": recipient.to_string(),
                     })
                     .to_string())
                 } else {
                     Ok(format!(
-                        "Transaction sent!\n  Digest: {}\n  Status: {}\n  Amount: {} -> {}",
+                        "Transaction sent!\n  Digest: {}\n  Status: {}\n  Amount: {} IOTA -> {}",
+                        result.digest, result.status, amount, recipient,
+                    ))
+                }
+            }
+
+            Command::Batch { payments } => {
+                let payments_nanos: Vec<(Address, u64)> = payments
+                    .iter()
+                    .map(|(recipient, amount)| (*recipient, amount.as_nanos()))
+                    .collect();
+
+                let result = network
+                    .send_iota_batch(wallet.private_key(), wallet.address(), &payments_nanos)
+                    .await?;
+
+                if json_output {
+                    let json_payments: Vec<serde_json::Value> = payments
+                        .iter()
+                        .map(|(recipient, amount)| {
+                            serde_json::json!({
+                                "recipient": recipient.to_string(),
+                                "amount": amount,
+                            })
+                        })
+                        .collect();
+                    Ok(serde_json::json!({
+                        "digest": result.digest,
+                        "status": result.status,
+                        "payments": json_payments,
+                    })
+                    .to_string())
+                } else {
+                    let lines: Vec<String> = payments
+                        .iter()
+                        .map(|(recipient, amount)| format!("  {amount} IOTA -> {recipient}"))
+                        .collect();
+                    Ok(format!(
+                        "Batch transaction sent!\n  Digest: {}\n  Status: {}\n{}",
                         result.digest,
                         result.status,
-                        display::format_balance(*amount),
-                        recipient,
+                        lines.join("\n"),
                     ))
                 }
             }
@@ -170,7 +565,127 @@ impl Command {
                         .collect();
                     Ok(serde_json::to_string_pretty(&json_txs)?)
                 } else {
-                    Ok(display::format_transactions(&txs))
+                    Ok(display::format_transactions(&txs, None))
+                }
+            }
+
+            Command::Tokens => {
+                let tokens = network.get_token_balances(wallet.address()).await?;
+                if json_output {
+                    let json_tokens: Vec<serde_json::Value> = tokens
+                        .iter()
+                        .map(|t| {
+                            serde_json::json!({
+                                "coin_type": t.coin_type,
+                                "symbol": t.symbol,
+                                "decimals": t.decimals,
+                                "amount": t.amount,
+                                "formatted": display::format_token_amount(t.amount, t.decimals),
+                            })
+                        })
+                        .collect();
+                    Ok(serde_json::to_string_pretty(&json_tokens)?)
+                } else if tokens.is_empty() {
+                    Ok("No tokens held.".to_string())
+                } else {
+                    let mut out = String::new();
+                    for t in &tokens {
+                        let label = t.symbol.as_deref().unwrap_or(t.coin_type.as_str());
+                        out.push_str(&format!(
+                            "{}\n  Type: {}\n  Amount: {}\n",
+                            label,
+                            t.coin_type,
+                            display::format_token_amount(t.amount, t.decimals),
+                        ));
+                    }
+                    Ok(out.trim_end().to_string())
+                }
+            }
+
+            Command::TransferToken {
+                coin_type,
+                recipient,
+                amount,
+            } => {
+                let decimals = network.coin_decimals(coin_type).await?;
+                let amount_units = display::parse_token_amount(amount, decimals)
+                    .map_err(|e| anyhow::anyhow!("Invalid amount '{amount}': {e}"))?;
+
+                if amount_units == 0 {
+                    bail!("Cannot send 0 tokens.");
+                }
+
+                let result = network
+                    .send_token(
+                        wallet.private_key(),
+                        wallet.address(),
+                        *recipient,
+                        coin_type,
+                        amount_units,
+                    )
+                    .await?;
+
+                if json_output {
+                    Ok(serde_json::json!({
+                        "digest": result.digest,
+                        "status": result.status,
+                        "coin_type": coin_type,
+                        "amount": amount_units,
+                        "recipient": recipient.to_string(),
+                    })
+                    .to_string())
+                } else {
+                    Ok(format!(
+                        "Transaction sent!\n  Digest: {}\n  Status: {}\n  Amount: {} {} -> {}",
+                        result.digest,
+                        result.status,
+                        display::format_token_amount(amount_units, decimals),
+                        coin_type,
+                        recipient,
+                    ))
+                }
+            }
+
+            Command::Confirm { digest } => {
+                let details = poll_until_final(network, digest).await?;
+                if json_output {
+                    Ok(serde_json::json!({
+                        "digest": details.digest,
+                        "status": details.status,
+                        "kind": details.kind,
+                        "sender": details.sender,
+                        "recipient": details.recipient,
+                        "amount": details.amount,
+                        "fee": details.fee,
+                        "balance_changes": details.balance_changes.deltas,
+                    })
+                    .to_string())
+                } else {
+                    let mut out = format!(
+                        "Digest: {}\n  Status: {}\n  Kind: {}",
+                        details.digest, details.status, details.kind
+                    );
+                    if let Some(sender) = &details.sender {
+                        out.push_str(&format!("\n  Sender: {sender}"));
+                    }
+                    if let Some(amount) = details.amount {
+                        out.push_str(&format!("\n  Amount: {amount} IOTA"));
+                    } else if !details.balance_changes.deltas.is_empty() {
+                        // No single recipient could be picked out (several
+                        // addresses credited, or only the sender's balance
+                        // moved): show each address's delta instead.
+                        for (address, delta) in &details.balance_changes.deltas {
+                            out.push_str(&format!(
+                                "\n  {address}: {}{}",
+                                if *delta >= 0 { "+" } else { "-" },
+                                display::nanos_to_iota(delta.unsigned_abs())
+                            ));
+                        }
+                    }
+                    if let Some(fee) = details.fee {
+                        out.push_str(&format!("\n  Fee: {} IOTA", display::nanos_to_iota(fee)));
+                    }
+                    Ok(out)
                 }
             }
 
@@ -205,9 +720,312 @@ impl Command {
                 }
             }
 
-            Command::Help { command } => Ok(help_text(command.as_deref())),
+            Command::Encrypt { password } => {
+                let password = resolve_password(password.as_deref(), io)?;
+                wallet.encrypt(&password)?;
+                if json_output {
+                    Ok(serde_json::json!({ "status": "encrypted" }).to_string())
+                } else {
+                    Ok("Wallet encrypted. Run 'unlock <password>' to sign transactions.".to_string())
+                }
+            }
+
+            Command::Unlock {
+                password,
+                ttl_minutes,
+            } => {
+                let password = resolve_password(password.as_deref(), io)?;
+                wallet.unlock(&password, *ttl_minutes)?;
+                if json_output {
+                    Ok(serde_json::json!({
+                        "status": "unlocked",
+                        "ttl_minutes": ttl_minutes,
+                    })
+                    .to_string())
+                } else {
+                    Ok(format!(
+                        "Wallet unlocked for {ttl_minutes} minute(s). It will auto-relock after that."
+                    ))
+                }
+            }
+
+            Command::Decrypt { password } => {
+                let password = resolve_password(password.as_deref(), io)?;
+                wallet.decrypt(&password)?;
+                if json_output {
+                    Ok(serde_json::json!({ "status": "decrypted" }).to_string())
+                } else {
+                    Ok("Wallet decrypted. The mnemonic and private key are now stored in the clear.".to_string())
+                }
+            }
+
+            Command::Stake { validator, amount } => {
+                let result = network
+                    .stake_iota(
+                        wallet.private_key(),
+                        wallet.address(),
+                        *validator,
+                        amount.as_nanos(),
+                    )
+                    .await?;
+                if json_output {
+                    Ok(serde_json::json!({
+                        "digest": result.digest,
+                        "status": result.status,
+                        "validator": validator.to_string(),
+                        "amount": amount,
+                    })
+                    .to_string())
+                } else {
+                    Ok(format!(
+                        "Stake submitted!\n  Digest: {}\n  Status: {}\n  Amount: {} -> validator {}",
+                        result.digest, result.status, amount, validator,
+                    ))
+                }
+            }
+
+            Command::Unstake { staked_object_id } => {
+                let result = network
+                    .unstake_iota(wallet.private_key(), wallet.address(), *staked_object_id)
+                    .await?;
+                if json_output {
+                    Ok(serde_json::json!({
+                        "digest": result.digest,
+                        "status": result.status,
+                        "staked_object_id": staked_object_id.to_string(),
+                    })
+                    .to_string())
+                } else {
+                    Ok(format!(
+                        "Unstake submitted!\n  Digest: {}\n  Status: {}\n  Object: {}",
+                        result.digest, result.status, staked_object_id,
+                    ))
+                }
+            }
+
+            Command::Stakes => {
+                let stakes = network.get_stakes(wallet.address()).await?;
+                if json_output {
+                    let json_stakes: Vec<serde_json::Value> = stakes
+                        .iter()
+                        .map(|s| {
+                            serde_json::json!({
+                                "object_id": s.object_id.to_string(),
+                                "pool_id": s.pool_id.to_string(),
+                                "principal": s.principal,
+                                "stake_activation_epoch": s.stake_activation_epoch,
+                                "estimated_reward": s.estimated_reward,
+                                "status": s.status.to_string(),
+                            })
+                        })
+                        .collect();
+                    Ok(serde_json::to_string_pretty(&json_stakes)?)
+                } else if stakes.is_empty() {
+                    Ok("No active stakes.".to_string())
+                } else {
+                    let mut out = String::new();
+                    for s in &stakes {
+                        out.push_str(&format!(
+                            "Object: {}\n  Pool: {}\n  Principal: {}\n  Activation epoch: {}\n  Status: {}",
+                            s.object_id,
+                            s.pool_id,
+                            display::nanos_to_iota(s.principal),
+                            s.stake_activation_epoch,
+                            s.status,
+                        ));
+                        if let Some(reward) = s.estimated_reward {
+                            out.push_str(&format!("\n  Estimated reward: {}", display::nanos_to_iota(reward)));
+                        }
+                        out.push('\n');
+                    }
+                    Ok(out.trim_end().to_string())
+                }
+            }
+
+            Command::Sweep { recipient } => {
+                let (result, amount) = network
+                    .sweep_all(wallet.private_key(), wallet.address(), *recipient)
+                    .await?;
+                if json_output {
+                    Ok(serde_json::json!({
+                        "digest": result.digest,
+                        "status": result.status,
+                        "amount": amount,
+                        "recipient": recipient.to_string(),
+                    })
+                    .to_string())
+                } else {
+                    Ok(format!(
+                        "Swept {} IOTA -> {}\n  Digest: {}\n  Status: {}",
+                        display::nanos_to_iota(amount),
+                        recipient,
+                        result.digest,
+                        result.status,
+                    ))
+                }
+            }
+
+            Command::AccountNew { label } => {
+                let account = wallet.add_account(label.clone())?;
+                if json_output {
+                    Ok(serde_json::json!({
+                        "index": account.index,
+                        "label": account.label,
+                        "address": account.address.to_string(),
+                    })
+                    .to_string())
+                } else {
+                    Ok(format!(
+                        "Created account {}\n  Address: {}",
+                        account.display_name(),
+                        account.address,
+                    ))
+                }
+            }
+
+            Command::AccountList => {
+                let accounts = wallet.accounts();
+                let mut rows = Vec::with_capacity(accounts.len());
+                for account in &accounts {
+                    let balance = network.balance(&account.address).await?;
+                    rows.push((account, balance));
+                }
+
+                if json_output {
+                    let json_accounts: Vec<serde_json::Value> = rows
+                        .iter()
+                        .map(|(account, balance)| {
+                            serde_json::json!({
+                                "index": account.index,
+                                "label": account.label,
+                                "address": account.address.to_string(),
+                                "balance": balance,
+                                "active": account.address == *wallet.address(),
+                            })
+                        })
+                        .collect();
+                    Ok(serde_json::to_string_pretty(&json_accounts)?)
+                } else if rows.is_empty() {
+                    Ok("No accounts.".to_string())
+                } else {
+                    let mut out = String::new();
+                    for (account, balance) in &rows {
+                        let marker = if account.address == *wallet.address() { "* " } else { "  " };
+                        out.push_str(&format!(
+                            "{marker}{}\n    Address: {}\n    Balance: {}\n",
+                            account.display_name(),
+                            account.address,
+                            display::nanos_to_iota(*balance),
+                        ));
+                    }
+                    Ok(out.trim_end().to_string())
+                }
+            }
+
+            Command::AccountUse { label_or_index } => {
+                let account = wallet.use_account(label_or_index)?;
+                if json_output {
+                    Ok(serde_json::json!({
+                        "status": "ok",
+                        "active": account.display_name(),
+                        "address": account.address.to_string(),
+                    })
+                    .to_string())
+                } else {
+                    Ok(format!(
+                        "Now using account {} ({})",
+                        account.display_name(),
+                        account.address,
+                    ))
+                }
+            }
+
+            Command::SyncOn { interval_secs } => {
+                let interval_secs = interval_secs.unwrap_or(DEFAULT_SYNC_INTERVAL_SECS);
+                if interval_secs == 0 {
+                    bail!("Sync interval must be greater than 0 seconds.");
+                }
+                wallet.start_sync(network, Duration::from_secs(interval_secs))?;
+                if json_output {
+                    Ok(serde_json::json!({
+                        "status": "syncing",
+                        "interval_secs": interval_secs,
+                    })
+                    .to_string())
+                } else {
+                    Ok(format!(
+                        "Background sync started (every {interval_secs}s). Run 'sync off' to stop."
+                    ))
+                }
+            }
+
+            Command::SyncOff => {
+                wallet.stop_sync();
+                if json_output {
+                    Ok(serde_json::json!({ "status": "stopped" }).to_string())
+                } else {
+                    Ok("Background sync stopped.".to_string())
+                }
+            }
+
+            Command::Help { command } => Ok(help_text(command.as_deref())),
+
+            Command::Exit => Ok(String::new()),
+        }
+    }
+}
+
+/// Initial delay before the first re-check in [`poll_until_final`].
+const CONFIRM_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+/// Upper bound the backoff doubles up to.
+const CONFIRM_MAX_BACKOFF: Duration = Duration::from_secs(5);
+/// Give up and report the last-seen status if nothing finalizes in time.
+const CONFIRM_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Poll `network.transaction_details(digest)` with exponential backoff until
+/// the transaction reaches a terminal (success/failure) status or
+/// [`CONFIRM_TIMEOUT`] elapses.
+async fn poll_until_final(
+    network: &NetworkClient,
+    digest: &Digest,
+) -> Result<TransactionDetailsSummary> {
+    let deadline = Instant::now() + CONFIRM_TIMEOUT;
+    let mut backoff = CONFIRM_INITIAL_BACKOFF;
 
-            Command::Exit => Ok(String::new()),
+    loop {
+        let details = network.transaction_details(digest).await?;
+        if is_terminal_status(&details.status) {
+            return Ok(details);
+        }
+
+        if Instant::now() >= deadline {
+            bail!(
+                "Timed out waiting for {digest} to finalize (last status: {}).",
+                details.status
+            );
+        }
+
+        sleep(backoff).await;
+        backoff = (backoff * 2).min(CONFIRM_MAX_BACKOFF);
+    }
+}
+
+/// Whether a transaction effects status string represents a terminal state
+/// (as opposed to still pending/executing).
+fn is_terminal_status(status: &str) -> bool {
+    let status = status.to_lowercase();
+    status.contains("success") || status.contains("fail")
+}
+
+/// Resolve a command's password, prompting through `io` without echo when
+/// it wasn't supplied on the command line.
+fn resolve_password(password: Option<&str>, io: &mut dyn WalletIo) -> Result<String> {
+    match password {
+        Some(password) => Ok(password.to_string()),
+        None => {
+            let password = io.prompt_password("Password: ");
+            crate::vault::require_nonempty_password(&password)?;
+            Ok(password)
         }
     }
 }
@@ -224,14 +1042,67 @@ pub fn help_text(command: Option<&str>) -> String {
         Some("transfer") | Some("send") => {
             "transfer <address> <amount>\n  Send IOTA to another address.\n  Amount is in IOTA (e.g. '1.5' for 1.5 IOTA).\n  Alias: send".to_string()
         }
+        Some("batch") | Some("send-batch") => {
+            "batch <address>:<amount> [<address>:<amount> ...]\n  Send IOTA to multiple \
+             recipients in a single transaction. Cheaper in gas than one transfer per \
+             recipient.\n  Alias: send-batch".to_string()
+        }
         Some("show_transfers") | Some("transfers") | Some("txs") => {
             "show_transfers [in|out|all]\n  Show transaction history.\n  Filter: 'in' (received), 'out' (sent), 'all' (default).\n  Aliases: transfers, txs".to_string()
         }
+        Some("tokens") => {
+            "tokens\n  List held coin types with their symbol (if known) and balance, \
+             formatted using each coin's own decimals.".to_string()
+        }
+        Some("transfer-token") | Some("send-token") => {
+            "transfer-token <coin_type> <address> <amount>\n  Send a non-IOTA coin type to \
+             another address.\n  Amount is parsed using the coin type's own decimals, \
+             not IOTA's.\n  Alias: send-token".to_string()
+        }
+        Some("confirm") | Some("status") | Some("verify") => {
+            "confirm <digest>\n  Poll a transaction until it finalizes or fails.\n  Aliases: status, verify".to_string()
+        }
         Some("faucet") => {
             "faucet\n  Request test tokens from the faucet.\n  Only available on testnet and devnet.".to_string()
         }
         Some("seed") => {
-            "seed\n  Display the wallet's seed phrase (mnemonic).\n  Keep this secret!".to_string()
+            "seed\n  Display the wallet's seed phrase (mnemonic).\n  Keep this secret!\n  Fails while the wallet is locked.".to_string()
+        }
+        Some("encrypt") => {
+            "encrypt [password]\n  Password-encrypt the mnemonic and private key at rest.\n  Prompted without echo if omitted.".to_string()
+        }
+        Some("unlock") => {
+            format!(
+                "unlock [password] [ttl_minutes]\n  Decrypt into memory for ttl_minutes (default {DEFAULT_UNLOCK_TTL_MINUTES}), after which the wallet auto-relocks.\n  Password prompted without echo if omitted."
+            )
+        }
+        Some("decrypt") => {
+            "decrypt [password]\n  Permanently remove at-rest encryption.\n  Prompted without echo if omitted.".to_string()
+        }
+        Some("stake") => {
+            "stake <validator> <amount>\n  Stake IOTA with a validator.\n  Amount is in IOTA (e.g. '100' for 100 IOTA).".to_string()
+        }
+        Some("unstake") => {
+            "unstake <staked_object_id>\n  Withdraw a stake and its rewards.".to_string()
+        }
+        Some("stakes") => {
+            "stakes\n  List this wallet's active and pending stakes.".to_string()
+        }
+        Some("sweep") => {
+            "sweep <address>\n  Send the entire balance to another address, minus gas.".to_string()
+        }
+        Some("account") => {
+            "account new [label]\n  Derive and add a new account from the wallet's mnemonic.\n\
+             account list\n  List known accounts with index, label, address and balance.\n\
+             account use <label_or_index>\n  Switch the active account.\n  Alias: ls for list"
+                .to_string()
+        }
+        Some("sync") => {
+            format!(
+                "sync on [interval_secs]\n  Start background polling of balance and \
+                 transaction history (default {DEFAULT_SYNC_INTERVAL_SECS}s).\n\
+                 sync off\n  Stop background polling."
+            )
         }
         Some("exit") | Some("quit") | Some("q") => {
             "exit\n  Exit the wallet.\n  Aliases: quit, q".to_string()
@@ -243,9 +1114,22 @@ pub fn help_text(command: Option<&str>) -> String {
              \x20 balance          Show wallet balance\n\
              \x20 address          Show wallet address\n\
              \x20 transfer         Send IOTA to an address\n\
+             \x20 batch            Send IOTA to multiple recipients in one transaction\n\
              \x20 show_transfers   Show transaction history\n\
+             \x20 tokens           List held coin types and balances\n\
+             \x20 transfer-token   Send a non-IOTA coin type to an address\n\
+             \x20 confirm          Poll a transaction until it finalizes\n\
              \x20 faucet           Request testnet/devnet tokens\n\
              \x20 seed             Show seed phrase\n\
+             \x20 encrypt          Password-encrypt the wallet at rest\n\
+             \x20 unlock           Decrypt into memory for a session\n\
+             \x20 decrypt          Permanently remove at-rest encryption\n\
+             \x20 stake            Stake IOTA with a validator\n\
+             \x20 unstake          Withdraw a stake and its rewards\n\
+             \x20 stakes           List active and pending stakes\n\
+             \x20 sweep            Send the entire balance to an address\n\
+             \x20 account          Manage accounts (new/list/use)\n\
+             \x20 sync             Toggle background balance/history polling\n\
              \x20 help [cmd]       Show help for a command\n\
              \x20 exit             Exit the wallet\n\
              \n\
@@ -284,7 +1168,7 @@ mod tests {
                     format!("{recipient}"),
                     "0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900"
                 );
-                assert_eq!(amount, 1_500_000_000);
+                assert_eq!(amount.as_nanos(), 1_500_000_000);
             }
             other => panic!("expected Transfer, got {other:?}"),
         }
@@ -315,6 +1199,53 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn parse_batch() {
+        let cmd = Command::parse(
+            "batch 0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900:1.5 \
+             0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16901:2",
+        )
+        .unwrap();
+        match cmd {
+            Command::Batch { payments } => {
+                assert_eq!(payments.len(), 2);
+                assert_eq!(payments[0].1.as_nanos(), 1_500_000_000);
+                assert_eq!(payments[1].1.as_nanos(), 2_000_000_000);
+            }
+            other => panic!("expected Batch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_batch_alias() {
+        let cmd = Command::parse(
+            "send-batch 0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900:1",
+        )
+        .unwrap();
+        assert!(matches!(cmd, Command::Batch { .. }));
+    }
+
+    #[test]
+    fn parse_batch_missing_payments() {
+        assert!(Command::parse("batch").is_err());
+    }
+
+    #[test]
+    fn parse_batch_invalid_payment_format() {
+        let result = Command::parse(
+            "batch 0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900",
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_batch_zero_amount() {
+        let result = Command::parse(
+            "batch 0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900:0",
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn parse_show_transfers() {
         assert_eq!(
@@ -337,6 +1268,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_tokens() {
+        assert_eq!(Command::parse("tokens").unwrap(), Command::Tokens);
+    }
+
+    #[test]
+    fn parse_transfer_token() {
+        let cmd = Command::parse(
+            "transfer-token 0x2::custom::COIN 0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900 1.5",
+        )
+        .unwrap();
+        match cmd {
+            Command::TransferToken {
+                coin_type,
+                recipient,
+                amount,
+            } => {
+                assert_eq!(coin_type, "0x2::custom::COIN");
+                assert_eq!(
+                    format!("{recipient}"),
+                    "0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900"
+                );
+                assert_eq!(amount, "1.5");
+            }
+            other => panic!("expected TransferToken, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_transfer_token_alias() {
+        let cmd = Command::parse(
+            "send-token 0x2::custom::COIN 0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900 2",
+        )
+        .unwrap();
+        assert!(matches!(cmd, Command::TransferToken { .. }));
+    }
+
+    #[test]
+    fn parse_transfer_token_missing_amount() {
+        let result = Command::parse(
+            "transfer-token 0x2::custom::COIN 0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900",
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_confirm() {
+        let digest = "0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900";
+        let cmd = Command::parse(&format!("confirm {digest}")).unwrap();
+        assert_eq!(
+            cmd,
+            Command::Confirm {
+                digest: digest.parse().unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_confirm_aliases() {
+        let digest = "0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900";
+        assert!(matches!(
+            Command::parse(&format!("status {digest}")).unwrap(),
+            Command::Confirm { .. }
+        ));
+        assert!(matches!(
+            Command::parse(&format!("verify {digest}")).unwrap(),
+            Command::Confirm { .. }
+        ));
+    }
+
+    #[test]
+    fn parse_confirm_missing_digest() {
+        assert!(Command::parse("confirm").is_err());
+    }
+
     #[test]
     fn parse_faucet() {
         assert_eq!(Command::parse("faucet").unwrap(), Command::Faucet);
@@ -347,6 +1353,234 @@ mod tests {
         assert_eq!(Command::parse("seed").unwrap(), Command::Seed);
     }
 
+    #[test]
+    fn parse_encrypt() {
+        assert_eq!(
+            Command::parse("encrypt hunter2").unwrap(),
+            Command::Encrypt {
+                password: Some("hunter2".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn parse_encrypt_without_password_prompts_later() {
+        assert_eq!(
+            Command::parse("encrypt").unwrap(),
+            Command::Encrypt { password: None }
+        );
+    }
+
+    #[test]
+    fn parse_unlock_default_ttl() {
+        assert_eq!(
+            Command::parse("unlock hunter2").unwrap(),
+            Command::Unlock {
+                password: Some("hunter2".to_string()),
+                ttl_minutes: DEFAULT_UNLOCK_TTL_MINUTES,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_unlock_custom_ttl() {
+        assert_eq!(
+            Command::parse("unlock hunter2 60").unwrap(),
+            Command::Unlock {
+                password: Some("hunter2".to_string()),
+                ttl_minutes: 60,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_unlock_invalid_ttl() {
+        assert!(Command::parse("unlock hunter2 soon").is_err());
+    }
+
+    #[test]
+    fn parse_decrypt() {
+        assert_eq!(
+            Command::parse("decrypt hunter2").unwrap(),
+            Command::Decrypt {
+                password: Some("hunter2".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn parse_stake() {
+        let cmd = Command::parse(
+            "stake 0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900 100",
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            Command::Stake {
+                validator: Address::from_hex(
+                    "0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900"
+                )
+                .unwrap(),
+                amount: IotaAmount::from_iota(100),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_stake_zero_amount() {
+        assert!(Command::parse(
+            "stake 0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900 0"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn parse_stake_missing_args() {
+        assert!(Command::parse("stake").is_err());
+        assert!(Command::parse(
+            "stake 0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn parse_unstake() {
+        let cmd = Command::parse(
+            "unstake 0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900",
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            Command::Unstake {
+                staked_object_id: ObjectId::from_hex(
+                    "0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900"
+                )
+                .unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_unstake_missing_object() {
+        assert!(Command::parse("unstake").is_err());
+    }
+
+    #[test]
+    fn parse_stakes() {
+        assert_eq!(Command::parse("stakes").unwrap(), Command::Stakes);
+    }
+
+    #[test]
+    fn parse_sweep() {
+        let cmd = Command::parse(
+            "sweep 0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900",
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            Command::Sweep {
+                recipient: Address::from_hex(
+                    "0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900"
+                )
+                .unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_sweep_missing_recipient() {
+        assert!(Command::parse("sweep").is_err());
+    }
+
+    #[test]
+    fn parse_account_new() {
+        assert_eq!(
+            Command::parse("account new").unwrap(),
+            Command::AccountNew { label: None }
+        );
+        assert_eq!(
+            Command::parse("account new Savings").unwrap(),
+            Command::AccountNew {
+                label: Some("Savings".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn parse_account_list() {
+        assert_eq!(Command::parse("account list").unwrap(), Command::AccountList);
+        assert_eq!(Command::parse("account ls").unwrap(), Command::AccountList);
+    }
+
+    #[test]
+    fn parse_account_use() {
+        assert_eq!(
+            Command::parse("account use Savings").unwrap(),
+            Command::AccountUse {
+                label_or_index: "Savings".to_string()
+            }
+        );
+        assert_eq!(
+            Command::parse("account use 2").unwrap(),
+            Command::AccountUse {
+                label_or_index: "2".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_account_missing_subcommand() {
+        assert!(Command::parse("account").is_err());
+    }
+
+    #[test]
+    fn parse_account_use_missing_target() {
+        assert!(Command::parse("account use").is_err());
+    }
+
+    #[test]
+    fn parse_account_unknown_subcommand() {
+        let result = Command::parse("account frobnicate");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("frobnicate"));
+    }
+
+    #[test]
+    fn parse_sync_on() {
+        assert_eq!(
+            Command::parse("sync on").unwrap(),
+            Command::SyncOn { interval_secs: None }
+        );
+        assert_eq!(
+            Command::parse("sync on 60").unwrap(),
+            Command::SyncOn {
+                interval_secs: Some(60)
+            }
+        );
+    }
+
+    #[test]
+    fn parse_sync_on_invalid_interval() {
+        assert!(Command::parse("sync on soon").is_err());
+    }
+
+    #[test]
+    fn parse_sync_off() {
+        assert_eq!(Command::parse("sync off").unwrap(), Command::SyncOff);
+    }
+
+    #[test]
+    fn parse_sync_missing_subcommand() {
+        assert!(Command::parse("sync").is_err());
+    }
+
+    #[test]
+    fn parse_sync_unknown_subcommand() {
+        let result = Command::parse("sync frobnicate");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("frobnicate"));
+    }
+
     #[test]
     fn parse_help() {
         assert_eq!(
@@ -416,4 +1650,103 @@ mod tests {
         assert!(!Command::Balance.requires_confirmation());
         assert!(!Command::Address.requires_confirmation());
     }
+
+    #[test]
+    fn decrypt_requires_confirmation() {
+        assert!(Command::Decrypt {
+            password: Some("x".to_string())
+        }
+        .requires_confirmation());
+        assert!(!Command::Encrypt {
+            password: Some("x".to_string())
+        }
+        .requires_confirmation());
+        assert!(!Command::Unlock {
+            password: Some("x".to_string()),
+            ttl_minutes: 5
+        }
+        .requires_confirmation());
+    }
+
+    #[test]
+    fn staking_commands_require_confirmation() {
+        let validator = Address::from_hex(
+            "0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900",
+        )
+        .unwrap();
+        assert!(Command::Stake {
+            validator,
+            amount: IotaAmount::from_iota(1),
+        }
+        .requires_confirmation());
+        assert!(Command::Unstake {
+            staked_object_id: ObjectId::from_hex(
+                "0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900"
+            )
+            .unwrap(),
+        }
+        .requires_confirmation());
+        assert!(Command::Sweep { recipient: validator }.requires_confirmation());
+        assert!(!Command::Stakes.requires_confirmation());
+    }
+
+    #[test]
+    fn terminal_status_detection() {
+        assert!(is_terminal_status("Success"));
+        assert!(is_terminal_status("Failure(InsufficientGas)"));
+        assert!(!is_terminal_status("Pending"));
+        assert!(!is_terminal_status("Executing"));
+    }
+
+    #[test]
+    fn spending_commands_require_unlocked() {
+        assert!(Command::Seed.requires_unlocked());
+        assert!(Command::Faucet.requires_unlocked());
+        assert!(Command::Transfer {
+            recipient: Address::from_hex(
+                "0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900"
+            )
+            .unwrap(),
+            amount: IotaAmount::from_iota(1),
+        }
+        .requires_unlocked());
+        assert!(Command::Batch {
+            payments: vec![(
+                Address::from_hex(
+                    "0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900"
+                )
+                .unwrap(),
+                IotaAmount::from_iota(1),
+            )],
+        }
+        .requires_unlocked());
+        assert!(!Command::Balance.requires_unlocked());
+        assert!(!Command::Address.requires_unlocked());
+        assert!(!Command::ShowTransfers {
+            filter: TransactionFilter::All
+        }
+        .requires_unlocked());
+    }
+
+    #[test]
+    fn staking_commands_require_unlocked() {
+        let validator = Address::from_hex(
+            "0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900",
+        )
+        .unwrap();
+        assert!(Command::Stake {
+            validator,
+            amount: IotaAmount::from_iota(1),
+        }
+        .requires_unlocked());
+        assert!(Command::Unstake {
+            staked_object_id: ObjectId::from_hex(
+                "0x0000a4984bd495d4346fa208ddff4f5d5e5ad48c21dec631ddebc99809f16900"
+            )
+            .unwrap(),
+        }
+        .requires_unlocked());
+        assert!(Command::Sweep { recipient: validator }.requires_unlocked());
+        assert!(!Command::Stakes.requires_unlocked());
+    }
 }
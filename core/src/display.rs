@@ -1,10 +1,79 @@
 /// Output formatting — IOTA denomination conversion and display helpers.
 ///
 /// IOTA uses 9 decimal places (nanos). 1 IOTA = 1_000_000_000 nanos.
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+use crate::labels::Labels;
 use crate::network::TransactionSummary;
 
 const NANOS_PER_IOTA: u64 = 1_000_000_000;
 
+/// A typed IOTA amount, always expressed internally as whole nanos.
+///
+/// Keeping this as a newtype instead of a bare `u64` stops callers from
+/// accidentally mixing nanos and whole-IOTA values, and routes amount math
+/// through checked arithmetic instead of ad-hoc `u64` ops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IotaAmount(u64);
+
+impl IotaAmount {
+    pub const ZERO: IotaAmount = IotaAmount(0);
+
+    /// Construct an amount from a raw nanos value.
+    #[must_use]
+    pub const fn from_nanos(nanos: u64) -> Self {
+        Self(nanos)
+    }
+
+    /// Construct an amount from a whole number of IOTA (no fractional part).
+    #[must_use]
+    pub fn from_iota(iota: u64) -> Self {
+        Self(iota.saturating_mul(NANOS_PER_IOTA))
+    }
+
+    /// The amount as raw nanos.
+    #[must_use]
+    pub const fn as_nanos(self) -> u64 {
+        self.0
+    }
+
+    #[must_use]
+    pub fn checked_add(self, other: IotaAmount) -> Option<IotaAmount> {
+        self.0.checked_add(other.0).map(IotaAmount)
+    }
+
+    #[must_use]
+    pub fn checked_sub(self, other: IotaAmount) -> Option<IotaAmount> {
+        self.0.checked_sub(other.0).map(IotaAmount)
+    }
+
+    #[must_use]
+    pub fn checked_mul(self, factor: u64) -> Option<IotaAmount> {
+        self.0.checked_mul(factor).map(IotaAmount)
+    }
+}
+
+impl fmt::Display for IotaAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&nanos_to_iota(self.0))
+    }
+}
+
+impl Serialize for IotaAmount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IotaAmount", 2)?;
+        state.serialize_field("balance_nanos", &self.0)?;
+        state.serialize_field("balance_iota", &nanos_to_iota(self.0))?;
+        state.end()
+    }
+}
+
 /// Convert nanos to a human-readable IOTA string.
 /// Examples: 1_500_000_000 -> "1.500000000", 0 -> "0.000000000"
 #[must_use]
@@ -20,10 +89,17 @@ pub fn format_balance(nanos: u64) -> String {
     format!("{} IOTA", nanos_to_iota(nanos))
 }
 
-/// Parse a human-readable IOTA amount string into nanos.
-/// Accepts: "1.5" -> 1_500_000_000, "1" -> 1_000_000_000, "0.001" -> 1_000_000
+/// Ordered table of unit suffixes accepted by [`parse_iota_amount`] and
+/// produced by [`format_balance_compact`], largest first so the compact
+/// formatter can pick the first one that fits.
+const UNIT_SUFFIXES: &[(&str, u64)] = &[("Gi", 1_000_000_000), ("Mi", 1_000_000), ("Ki", 1_000)];
+
+/// Parse a human-readable IOTA amount string into an [`IotaAmount`].
+/// Accepts: "1.5" -> 1_500_000_000 nanos, "1" -> 1_000_000_000 nanos, "0.001" -> 1_000_000 nanos.
+/// Also accepts a unit suffix that scales the decimal before conversion, e.g.
+/// "1.5 Gi" (1.5 billion IOTA), "250 Mi" (250 million IOTA), "0.3 Ki" (300 IOTA).
 #[must_use = "parsing result should be checked"]
-pub fn parse_iota_amount(input: &str) -> Result<u64, String> {
+pub fn parse_iota_amount(input: &str) -> Result<IotaAmount, String> {
     let input = input.trim();
 
     if input.is_empty() {
@@ -34,13 +110,43 @@ pub fn parse_iota_amount(input: &str) -> Result<u64, String> {
         return Err("Amount must be positive".to_string());
     }
 
+    for (suffix, factor) in UNIT_SUFFIXES {
+        if let Some(rest) = strip_suffix_ci(input, suffix) {
+            let nanos = parse_plain_iota(rest.trim())?;
+            let total = nanos
+                .checked_mul(*factor)
+                .ok_or_else(|| "Amount too large".to_string())?;
+            return Ok(IotaAmount::from_nanos(total));
+        }
+    }
+
+    Ok(IotaAmount::from_nanos(parse_plain_iota(input)?))
+}
+
+/// Case-insensitively strip a trailing unit suffix, returning the remainder.
+fn strip_suffix_ci<'a>(input: &'a str, suffix: &str) -> Option<&'a str> {
+    if input.len() >= suffix.len()
+        && input[input.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
+    {
+        Some(&input[..input.len() - suffix.len()])
+    } else {
+        None
+    }
+}
+
+/// Parse a plain (unsuffixed) decimal IOTA amount into nanos.
+fn parse_plain_iota(input: &str) -> Result<u64, String> {
+    if input.is_empty() {
+        return Err("Amount cannot be empty".to_string());
+    }
+
     // Check if it's purely numeric (nanos)
     if let Ok(nanos) = input.parse::<u64>() {
         // If the number is very large, assume it's nanos. If small, assume IOTA.
         // To avoid ambiguity, we always treat bare integers as IOTA.
-        return Ok(nanos.checked_mul(NANOS_PER_IOTA).ok_or_else(|| {
-            "Amount too large".to_string()
-        })?);
+        return nanos
+            .checked_mul(NANOS_PER_IOTA)
+            .ok_or_else(|| "Amount too large".to_string());
     }
 
     // Try parsing as decimal IOTA
@@ -71,17 +177,111 @@ pub fn parse_iota_amount(input: &str) -> Result<u64, String> {
         0
     };
 
-    let total = whole
+    whole
         .checked_mul(NANOS_PER_IOTA)
         .and_then(|w| w.checked_add(frac_nanos))
-        .ok_or_else(|| "Amount too large".to_string())?;
+        .ok_or_else(|| "Amount too large".to_string())
+}
 
-    Ok(total)
+/// Format a balance compactly, picking the largest unit suffix (see
+/// [`UNIT_SUFFIXES`]) whose value is at least 1, with trailing zeros trimmed.
+/// Falls back to a plain 2-decimal IOTA value when no suffix applies.
+/// Examples: 1_500_000_000_000_000_000 -> "1.5 Gi", 250_000_000_000_000_000 -> "250 Mi".
+#[must_use]
+pub fn format_balance_compact(nanos: u64) -> String {
+    for (suffix, factor) in UNIT_SUFFIXES {
+        let unit_nanos = NANOS_PER_IOTA * factor;
+        if nanos >= unit_nanos {
+            return format!("{} {suffix}", trimmed_decimal(nanos, unit_nanos));
+        }
+    }
+    trimmed_decimal(nanos, NANOS_PER_IOTA)
+}
+
+/// Render `value / unit` to two decimal places with trailing zeros trimmed.
+fn trimmed_decimal(value: u64, unit: u64) -> String {
+    let whole = value / unit;
+    let remainder = value % unit;
+    let frac = (u128::from(remainder) * 100 / u128::from(unit)) as u64;
+    let mut s = format!("{whole}.{frac:02}");
+    while s.ends_with('0') {
+        s.pop();
+    }
+    if s.ends_with('.') {
+        s.pop();
+    }
+    s
+}
+
+/// Format a raw base-unit token amount using its own decimal count, e.g.
+/// `(1_500_000, 6) -> "1.500000"`. Unlike [`nanos_to_iota`], `decimals` isn't
+/// fixed at 9: non-IOTA coin types report their own denomination.
+#[must_use]
+pub fn format_token_amount(amount: u64, decimals: u8) -> String {
+    if decimals == 0 {
+        return amount.to_string();
+    }
+    let factor = 10u64.saturating_pow(u32::from(decimals));
+    let whole = amount / factor;
+    let frac = amount % factor;
+    format!("{whole}.{frac:0width$}", width = decimals as usize)
+}
+
+/// Parse a human-readable decimal amount into base units for a coin type
+/// with `decimals` decimal places. Generalizes [`parse_iota_amount`]'s
+/// fixed-9-decimals logic to an arbitrary denomination.
+#[must_use = "parsing result should be checked"]
+pub fn parse_token_amount(input: &str, decimals: u8) -> Result<u64, String> {
+    let input = input.trim();
+
+    if input.is_empty() {
+        return Err("Amount cannot be empty".to_string());
+    }
+    if input.starts_with('-') {
+        return Err("Amount must be positive".to_string());
+    }
+
+    let parts: Vec<&str> = input.split('.').collect();
+    if parts.len() > 2 {
+        return Err("Invalid amount format. Use decimal units like '1.5' or '0.001'.".to_string());
+    }
+
+    let whole: u64 = parts[0]
+        .parse()
+        .map_err(|_| format!("Invalid whole part: '{}'", parts[0]))?;
+    let factor = 10u64.saturating_pow(u32::from(decimals));
+
+    let frac_units = if parts.len() == 2 {
+        let frac_str = parts[1];
+        if frac_str.is_empty() {
+            0
+        } else if frac_str.len() > decimals as usize {
+            return Err(format!(
+                "Too many decimal places. This token supports up to {decimals}."
+            ));
+        } else {
+            let padded = format!("{frac_str:0<width$}", width = decimals as usize);
+            padded
+                .parse::<u64>()
+                .map_err(|_| format!("Invalid fractional part: '{frac_str}'"))?
+        }
+    } else {
+        0
+    };
+
+    whole
+        .checked_mul(factor)
+        .and_then(|w| w.checked_add(frac_units))
+        .ok_or_else(|| "Amount too large".to_string())
 }
 
 /// Format a list of transactions for display.
+///
+/// When `labels` is given, a saved label for a transaction's digest (or its
+/// sender's address) is shown alongside the raw value rather than replacing
+/// it, so the digest/address remains available to copy.
 #[must_use]
-pub fn format_transactions(txs: &[TransactionSummary]) -> String {
+pub fn format_transactions(txs: &[TransactionSummary], labels: Option<&Labels>) -> String {
     if txs.is_empty() {
         return "No transactions found.".to_string();
     }
@@ -91,14 +291,20 @@ pub fn format_transactions(txs: &[TransactionSummary]) -> String {
     output.push_str(&format!("{:-<66}  {:-<20}\n", "", ""));
     for tx in txs {
         output.push_str(&format!("{:<66}  {}", tx.digest, tx.kind));
+        if let Some(label) = labels.and_then(|l| l.get(&tx.digest)) {
+            output.push_str(&format!("  \"{label}\""));
+        }
         if let Some(ts) = &tx.timestamp {
             output.push_str(&format!("  [{ts}]"));
         }
         if let Some(sender) = &tx.sender {
             output.push_str(&format!("  from {sender}"));
+            if let Some(label) = labels.and_then(|l| l.get(sender)) {
+                output.push_str(&format!(" ({label})"));
+            }
         }
         if let Some(amount) = tx.amount {
-            output.push_str(&format!("  {}", format_balance(amount)));
+            output.push_str(&format!("  {} IOTA", amount));
         }
         output.push('\n');
     }
@@ -160,22 +366,25 @@ mod tests {
 
     #[test]
     fn parse_whole_number() {
-        assert_eq!(parse_iota_amount("1").unwrap(), 1_000_000_000);
+        assert_eq!(parse_iota_amount("1").unwrap().as_nanos(), 1_000_000_000);
     }
 
     #[test]
     fn parse_decimal() {
-        assert_eq!(parse_iota_amount("1.5").unwrap(), 1_500_000_000);
+        assert_eq!(parse_iota_amount("1.5").unwrap().as_nanos(), 1_500_000_000);
     }
 
     #[test]
     fn parse_small_decimal() {
-        assert_eq!(parse_iota_amount("0.001").unwrap(), 1_000_000);
+        assert_eq!(parse_iota_amount("0.001").unwrap().as_nanos(), 1_000_000);
     }
 
     #[test]
     fn parse_full_precision() {
-        assert_eq!(parse_iota_amount("1.123456789").unwrap(), 1_123_456_789);
+        assert_eq!(
+            parse_iota_amount("1.123456789").unwrap().as_nanos(),
+            1_123_456_789
+        );
     }
 
     #[test]
@@ -195,12 +404,12 @@ mod tests {
 
     #[test]
     fn parse_zero() {
-        assert_eq!(parse_iota_amount("0").unwrap(), 0);
+        assert_eq!(parse_iota_amount("0").unwrap().as_nanos(), 0);
     }
 
     #[test]
     fn parse_zero_decimal() {
-        assert_eq!(parse_iota_amount("0.0").unwrap(), 0);
+        assert_eq!(parse_iota_amount("0.0").unwrap().as_nanos(), 0);
     }
 
     #[test]
@@ -217,7 +426,7 @@ mod tests {
 
     #[test]
     fn parse_trailing_dot() {
-        assert_eq!(parse_iota_amount("1.").unwrap(), 1_000_000_000);
+        assert_eq!(parse_iota_amount("1.").unwrap().as_nanos(), 1_000_000_000);
     }
 
     #[test]
@@ -228,9 +437,112 @@ mod tests {
         assert_eq!(v["balance_iota"], "1.500000000");
     }
 
+    #[test]
+    fn iota_amount_checked_arithmetic() {
+        let a = IotaAmount::from_iota(1);
+        let b = IotaAmount::from_nanos(500_000_000);
+        assert_eq!(a.checked_add(b).unwrap().as_nanos(), 1_500_000_000);
+        assert_eq!(a.checked_sub(b).unwrap().as_nanos(), 500_000_000);
+        assert!(b.checked_sub(a).is_none());
+        assert_eq!(IotaAmount::from_nanos(u64::MAX).checked_add(a), None);
+    }
+
+    #[test]
+    fn parse_suffix_gi() {
+        assert_eq!(
+            parse_iota_amount("1.5 Gi").unwrap().as_nanos(),
+            1_500_000_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn parse_suffix_mi() {
+        assert_eq!(
+            parse_iota_amount("250 Mi").unwrap().as_nanos(),
+            250_000_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn parse_suffix_ki_no_space() {
+        assert_eq!(
+            parse_iota_amount("0.3Ki").unwrap().as_nanos(),
+            300_000_000_000
+        );
+    }
+
+    #[test]
+    fn parse_suffix_case_insensitive() {
+        assert_eq!(
+            parse_iota_amount("1 gi").unwrap().as_nanos(),
+            1_000_000_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn format_compact_gi() {
+        assert_eq!(
+            format_balance_compact(1_500_000_000_000_000_000),
+            "1.5 Gi"
+        );
+    }
+
+    #[test]
+    fn format_compact_mi() {
+        assert_eq!(
+            format_balance_compact(250_000_000_000_000_000),
+            "250 Mi"
+        );
+    }
+
+    #[test]
+    fn format_compact_below_smallest_unit() {
+        assert_eq!(format_balance_compact(1_500_000_000), "1.5");
+    }
+
+    #[test]
+    fn iota_amount_display_and_serde() {
+        let amount = IotaAmount::from_nanos(1_500_000_000);
+        assert_eq!(amount.to_string(), "1.500000000");
+
+        let json = serde_json::to_value(amount).unwrap();
+        assert_eq!(json["balance_nanos"], 1_500_000_000u64);
+        assert_eq!(json["balance_iota"], "1.500000000");
+    }
+
+    #[test]
+    fn format_token_amount_six_decimals() {
+        assert_eq!(format_token_amount(1_500_000, 6), "1.500000");
+    }
+
+    #[test]
+    fn format_token_amount_zero_decimals() {
+        assert_eq!(format_token_amount(42, 0), "42");
+    }
+
+    #[test]
+    fn parse_token_amount_six_decimals() {
+        assert_eq!(parse_token_amount("1.5", 6).unwrap(), 1_500_000);
+    }
+
+    #[test]
+    fn parse_token_amount_zero_decimals() {
+        assert_eq!(parse_token_amount("42", 0).unwrap(), 42);
+    }
+
+    #[test]
+    fn parse_token_amount_too_many_decimals() {
+        assert!(parse_token_amount("1.5", 0).is_err());
+    }
+
+    #[test]
+    fn parse_token_amount_negative_fails() {
+        assert!(parse_token_amount("-1", 6).is_err());
+    }
+
     #[test]
     fn format_empty_transactions() {
-        assert_eq!(format_transactions(&[]), "No transactions found.");
+        assert_eq!(format_transactions(&[], None), "No transactions found.");
     }
 
     #[test]
@@ -244,8 +556,23 @@ mod tests {
                 amount: None,
             },
         ];
-        let output = format_transactions(&txs);
+        let output = format_transactions(&txs, None);
         assert!(output.contains("abc123"));
         assert!(output.contains("transfer"));
     }
+
+    #[test]
+    fn format_transactions_shows_label() {
+        let txs = vec![TransactionSummary {
+            digest: "abc123".to_string(),
+            kind: "transfer".to_string(),
+            timestamp: None,
+            sender: None,
+            amount: None,
+        }];
+        let mut labels = Labels::new();
+        labels.set("abc123", "Payroll");
+        let output = format_transactions(&txs, Some(&labels));
+        assert!(output.contains("\"Payroll\""));
+    }
 }
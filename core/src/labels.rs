@@ -0,0 +1,122 @@
+/// Local labels for addresses and transaction digests.
+///
+/// Labels are a purely local annotation layer: a user-chosen name mapped to
+/// an address or a transaction digest, persisted alongside the wallet data
+/// so the wallet can show "Alice" instead of a raw hex digest.
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::lock::{self, DEFAULT_LOCK_TIMEOUT};
+
+/// A store of user-assigned labels, keyed by address or transaction digest.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct Labels {
+    entries: HashMap<String, String>,
+}
+
+impl Labels {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load labels from `path`, returning an empty store if the file doesn't exist yet.
+    ///
+    /// Holds a shared lock on `path` for the duration of the read; see
+    /// [`crate::lock`].
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::new());
+        }
+        lock::with_shared(path, DEFAULT_LOCK_TIMEOUT, || {
+            let data = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read labels file: {}", path.display()))?;
+            serde_json::from_str(&data)
+                .with_context(|| format!("Failed to parse labels file: {}", path.display()))
+        })
+    }
+
+    /// Persist the labels to `path`, creating parent directories if needed.
+    ///
+    /// Holds an exclusive lock on `path` for the duration of the write, so
+    /// two concurrent wallet processes can't interleave writes; see
+    /// [`crate::lock`].
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create labels directory: {}", parent.display()))?;
+        }
+        let data = serde_json::to_string_pretty(self)?;
+        lock::with_exclusive(path, DEFAULT_LOCK_TIMEOUT, || {
+            fs::write(path, &data)
+                .with_context(|| format!("Failed to write labels file: {}", path.display()))
+        })
+    }
+
+    /// Look up the label for an address or transaction digest, if any.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+
+    /// Set (or overwrite) the label for an address or transaction digest.
+    pub fn set(&mut self, key: impl Into<String>, label: impl Into<String>) {
+        self.entries.insert(key.into(), label.into());
+    }
+
+    /// Remove the label for an address or transaction digest, if present.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.entries.remove(key)
+    }
+
+    /// The default labels file path alongside a named wallet's data directory.
+    #[must_use]
+    pub fn default_path(wallet_dir: &Path) -> PathBuf {
+        wallet_dir.join("labels.json")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_and_get_roundtrip() {
+        let mut labels = Labels::new();
+        labels.set("0xabc123", "Exchange hot wallet");
+        assert_eq!(labels.get("0xabc123"), Some("Exchange hot wallet"));
+        assert_eq!(labels.get("0xdef456"), None);
+    }
+
+    #[test]
+    fn remove_label() {
+        let mut labels = Labels::new();
+        labels.set("digest1", "Payroll");
+        assert_eq!(labels.remove("digest1"), Some("Payroll".to_string()));
+        assert_eq!(labels.get("digest1"), None);
+    }
+
+    #[test]
+    fn load_missing_file_is_empty() {
+        let labels = Labels::load(Path::new("/nonexistent/labels.json")).unwrap();
+        assert_eq!(labels.get("anything"), None);
+    }
+
+    #[test]
+    fn save_and_load_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("iota-wallet-labels-test-{}", std::process::id()));
+        let path = Labels::default_path(&dir);
+
+        let mut labels = Labels::new();
+        labels.set("0xabc123", "Exchange hot wallet");
+        labels.save(&path).unwrap();
+
+        let loaded = Labels::load(&path).unwrap();
+        assert_eq!(loaded.get("0xabc123"), Some("Exchange hot wallet"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -1,6 +1,13 @@
+pub mod account;
 pub mod commands;
 pub mod display;
+pub mod io;
+pub mod labels;
+pub mod live;
+pub mod lock;
 pub mod network;
+pub mod sync;
+pub mod vault;
 pub mod wallet;
 pub mod wallet_file;
 
@@ -0,0 +1,253 @@
+/// Live balance/transaction updates, so the GUI doesn't depend on the user
+/// pressing refresh.
+///
+/// Subscribes to the node's MQTT broker when available and falls back to
+/// polling otherwise (some custom nodes don't run a broker at all, hence the
+/// `mqtt_enabled` toggle on [`LiveStreamConfig`]). A dropped MQTT connection
+/// is retried with exponential backoff before the stream gives up on MQTT
+/// for the rest of the session and polls instead.
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use futures::StreamExt;
+use iota_sdk::types::Address;
+use tokio::sync::mpsc;
+use tokio::time::sleep;
+
+use crate::display::IotaAmount;
+use crate::network::{NetworkClient, TransactionSummary};
+
+/// An incremental update pushed to a live stream's receiver.
+#[derive(Debug, Clone)]
+pub enum LiveEvent {
+    /// The address's balance changed; carries the new total.
+    Balance(IotaAmount),
+    /// A new transaction involving the address was observed.
+    Transaction(TransactionSummary),
+    /// The MQTT connection dropped and a reconnect is being attempted.
+    Reconnecting { attempt: u32 },
+    /// MQTT isn't available on this node; the stream has fallen back to polling.
+    FellBackToPolling,
+}
+
+/// Tuning knobs for [`NetworkClient::watch_address`].
+#[derive(Debug, Clone)]
+pub struct LiveStreamConfig {
+    /// Try the node's MQTT broker first. When `false`, always poll.
+    /// Custom networks without a broker should set this to `false`.
+    pub mqtt_enabled: bool,
+    /// Interval between balance/transaction checks while polling.
+    pub poll_interval: Duration,
+    /// Delay before the first MQTT reconnect attempt, doubled on each
+    /// subsequent attempt up to `max_backoff`.
+    pub initial_backoff: Duration,
+    /// Upper bound on reconnect backoff.
+    pub max_backoff: Duration,
+    /// Consecutive failed reconnect attempts before giving up on MQTT and
+    /// falling back to polling for the rest of the session.
+    pub max_reconnect_attempts: u32,
+}
+
+impl Default for LiveStreamConfig {
+    fn default() -> Self {
+        Self {
+            mqtt_enabled: true,
+            poll_interval: Duration::from_secs(10),
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_reconnect_attempts: 5,
+        }
+    }
+}
+
+impl NetworkClient {
+    /// Start watching `address` for balance and transaction changes.
+    ///
+    /// Returns a receiver fed by a background task. The caller (the GUI's
+    /// update loop) forwards each [`LiveEvent`] into `Message::LiveUpdate`;
+    /// once the receiver is dropped, the task exits the next time it tries
+    /// to send an event.
+    pub fn watch_address(
+        &self,
+        address: Address,
+        config: LiveStreamConfig,
+    ) -> mpsc::Receiver<LiveEvent> {
+        let (tx, rx) = mpsc::channel(32);
+        let network = self.clone();
+        tokio::spawn(async move { run(network, address, config, tx).await });
+        rx
+    }
+
+    /// Open an MQTT subscription to the node for `address`'s balance and
+    /// transaction events. Fails if the node has no MQTT broker configured.
+    async fn subscribe_mqtt(&self, address: &Address) -> Result<MqttSubscription> {
+        let mqtt = iota_sdk::mqtt::MqttClient::connect(self.client().node_url())
+            .await
+            .context("Failed to connect to node's MQTT broker")?;
+        let mut events = mqtt
+            .subscribe_address(*address)
+            .await
+            .context("Failed to subscribe to address events")?;
+
+        let (tx, rx) = mpsc::channel(32);
+        tokio::spawn(async move {
+            while let Some(event) = events.next().await {
+                let mapped = match event {
+                    iota_sdk::mqtt::AddressEvent::BalanceChanged(nanos) => {
+                        LiveEvent::Balance(IotaAmount::from_nanos(nanos))
+                    }
+                    iota_sdk::mqtt::AddressEvent::Transaction(digest) => {
+                        LiveEvent::Transaction(TransactionSummary {
+                            digest,
+                            kind: "transaction".to_string(),
+                            timestamp: None,
+                            sender: None,
+                            amount: None,
+                        })
+                    }
+                };
+                if tx.send(mapped).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(MqttSubscription { rx })
+    }
+}
+
+/// A live MQTT subscription to a single address's events.
+struct MqttSubscription {
+    rx: mpsc::Receiver<LiveEvent>,
+}
+
+impl MqttSubscription {
+    async fn recv(&mut self) -> Option<LiveEvent> {
+        self.rx.recv().await
+    }
+}
+
+async fn run(
+    network: NetworkClient,
+    address: Address,
+    config: LiveStreamConfig,
+    tx: mpsc::Sender<LiveEvent>,
+) {
+    let mut mqtt_enabled = config.mqtt_enabled;
+    let mut last_balance: Option<IotaAmount> = None;
+    let mut known_digests: std::collections::HashSet<String> = std::collections::HashSet::new();
+
+    loop {
+        if mqtt_enabled {
+            match try_mqtt(&network, &address, &config, &tx).await {
+                MqttOutcome::GaveUp => {
+                    mqtt_enabled = false;
+                    if tx.send(LiveEvent::FellBackToPolling).await.is_err() {
+                        return;
+                    }
+                }
+                MqttOutcome::ReceiverDropped => return,
+            }
+            continue;
+        }
+
+        match poll_once(&network, &address, &mut last_balance, &mut known_digests).await {
+            Ok(events) => {
+                for event in events {
+                    if tx.send(event).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            Err(_) => {
+                // Transient query failure — try again after the usual interval
+                // rather than tearing down the stream.
+            }
+        }
+        sleep(config.poll_interval).await;
+    }
+}
+
+enum MqttOutcome {
+    /// MQTT failed `max_reconnect_attempts` times in a row; caller should
+    /// switch to polling.
+    GaveUp,
+    /// The receiver was dropped; the caller should stop entirely.
+    ReceiverDropped,
+}
+
+/// Subscribe to the node's MQTT broker and forward events until the
+/// connection drops, retrying with exponential backoff. Gives up after
+/// `config.max_reconnect_attempts` consecutive failures.
+async fn try_mqtt(
+    network: &NetworkClient,
+    address: &Address,
+    config: &LiveStreamConfig,
+    tx: &mpsc::Sender<LiveEvent>,
+) -> MqttOutcome {
+    let mut backoff = config.initial_backoff;
+    // Counts only *consecutive* failures, reset on a successful connection —
+    // a long-lived session that reconnects many times over its lifetime
+    // (each one following a healthy, hours-long subscription) should never
+    // exhaust this just because it's been running a while.
+    let mut consecutive_failures = 0u32;
+
+    while consecutive_failures < config.max_reconnect_attempts {
+        match network.subscribe_mqtt(address).await {
+            Ok(mut subscription) => {
+                while let Some(event) = subscription.recv().await {
+                    if tx.send(event).await.is_err() {
+                        return MqttOutcome::ReceiverDropped;
+                    }
+                }
+                // Broker closed the subscription — reconnect from attempt 1.
+                backoff = config.initial_backoff;
+                consecutive_failures = 0;
+            }
+            Err(_) => {
+                consecutive_failures += 1;
+                if tx
+                    .send(LiveEvent::Reconnecting {
+                        attempt: consecutive_failures,
+                    })
+                    .await
+                    .is_err()
+                {
+                    return MqttOutcome::ReceiverDropped;
+                }
+                sleep(backoff).await;
+                backoff = (backoff * 2).min(config.max_backoff);
+            }
+        }
+    }
+
+    MqttOutcome::GaveUp
+}
+
+/// Poll balance and recent transactions once, diffing against what was
+/// already reported so the caller only sees genuinely new events.
+async fn poll_once(
+    network: &NetworkClient,
+    address: &Address,
+    last_balance: &mut Option<IotaAmount>,
+    known_digests: &mut std::collections::HashSet<String>,
+) -> anyhow::Result<Vec<LiveEvent>> {
+    let mut events = Vec::new();
+
+    let balance = IotaAmount::from_nanos(network.balance(address).await?);
+    if *last_balance != Some(balance) {
+        *last_balance = Some(balance);
+        events.push(LiveEvent::Balance(balance));
+    }
+
+    let txs = network
+        .transactions(address, crate::network::TransactionFilter::All)
+        .await?;
+    for tx in txs {
+        if known_digests.insert(tx.digest.clone()) {
+            events.push(LiveEvent::Transaction(tx));
+        }
+    }
+
+    Ok(events)
+}
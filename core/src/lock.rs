@@ -0,0 +1,179 @@
+/// Advisory file locking so two concurrent wallet CLI invocations don't race
+/// on the same wallet-data file (the accounts book, labels, and eventually
+/// the transaction cache) and corrupt state.
+///
+/// Backed by the `fd-lock` crate, i.e. a real OS-level `flock(2)`/`LockFileEx`
+/// held on an open descriptor of a sidecar `<path>.lock` file, rather than
+/// a lock file whose mere existence is the lock: the kernel
+/// releases it the moment the holding process exits for any reason,
+/// including a crash or `SIGKILL`, so a killed wallet process can never
+/// leave every future invocation permanently deadlocked.
+use std::fs::{File, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use fd_lock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// How often to retry acquiring a contended lock.
+const RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How long callers wait for a contended wallet-data file by default before
+/// giving up with "another wallet process is running".
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// An advisory lock (exclusive or shared) on a file, opened once and reused across
+/// however many critical sections a wallet-data file needs guarded.
+pub struct FileLock {
+    inner: RwLock<File>,
+    path: PathBuf,
+}
+
+impl FileLock {
+    /// Open (creating if needed) `path` as the target of a lock, without
+    /// acquiring it yet.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open {} for locking", path.display()))?;
+        Ok(Self {
+            inner: RwLock::new(file),
+            path,
+        })
+    }
+
+    /// Acquire an exclusive lock, blocking until it's free or `timeout`
+    /// elapses. Drop the returned guard to release it.
+    ///
+    /// Returns a clear "another wallet process is running" error on
+    /// timeout, rather than letting two invocations silently interleave
+    /// writes to the same file.
+    pub fn lock_exclusive(&mut self, timeout: Duration) -> Result<RwLockWriteGuard<'_, File>> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.inner.try_write() {
+                Ok(guard) => return Ok(guard),
+                Err(_) => {
+                    if Instant::now() >= deadline {
+                        bail!(
+                            "Another wallet process is running (lock held on {}). \
+                             Try again once it exits.",
+                            self.path.display()
+                        );
+                    }
+                    std::thread::sleep(RETRY_INTERVAL);
+                }
+            }
+        }
+    }
+
+    /// Acquire a shared (read) lock, blocking until it's free of writers or
+    /// `timeout` elapses. Multiple readers may hold this at once.
+    pub fn lock_shared(&mut self, timeout: Duration) -> Result<RwLockReadGuard<'_, File>> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.inner.try_read() {
+                Ok(guard) => return Ok(guard),
+                Err(_) => {
+                    if Instant::now() >= deadline {
+                        bail!(
+                            "Another wallet process is running (lock held on {}). \
+                             Try again once it exits.",
+                            self.path.display()
+                        );
+                    }
+                    std::thread::sleep(RETRY_INTERVAL);
+                }
+            }
+        }
+    }
+}
+
+/// Run `f` while holding an exclusive lock on `path`, opening the lock file
+/// fresh each call. Convenient for one-off critical sections like
+/// [`crate::account::AccountBook::save`] where the caller doesn't otherwise
+/// need to keep a [`FileLock`] around between calls.
+pub fn with_exclusive<T>(path: &Path, timeout: Duration, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let mut lock = FileLock::open(lock_path_for(path))?;
+    let _guard = lock.lock_exclusive(timeout)?;
+    f()
+}
+
+/// Run `f` while holding a shared lock on `path`, opening the lock file
+/// fresh each call. Convenient for one-off reads like
+/// [`crate::account::AccountBook::load`].
+pub fn with_shared<T>(path: &Path, timeout: Duration, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let mut lock = FileLock::open(lock_path_for(path))?;
+    let _guard = lock.lock_shared(timeout)?;
+    f()
+}
+
+/// The sidecar path a data file is locked through, so the lock itself never
+/// competes with reads/writes of the data file's own contents.
+fn lock_path_for(path: &Path) -> PathBuf {
+    let mut os = path.as_os_str().to_owned();
+    os.push(".lock");
+    PathBuf::from(os)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "iota-wallet-lock-test-{}-{name}",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn acquires_and_releases() {
+        let path = test_path("acquires_and_releases");
+        let _ = std::fs::remove_file(lock_path_for(&path));
+
+        let mut lock = FileLock::open(lock_path_for(&path)).unwrap();
+        {
+            let _guard = lock.lock_exclusive(Duration::from_secs(1)).unwrap();
+        }
+        // Dropping the guard released the OS-level lock, so re-acquiring
+        // it immediately should succeed.
+        let _guard = lock.lock_exclusive(Duration::from_millis(50)).unwrap();
+    }
+
+    #[test]
+    fn contended_lock_times_out() {
+        let path = test_path("contended_lock_times_out");
+        let _ = std::fs::remove_file(lock_path_for(&path));
+
+        let mut holder = FileLock::open(lock_path_for(&path)).unwrap();
+        let _held = holder.lock_exclusive(Duration::from_secs(1)).unwrap();
+
+        let mut contender = FileLock::open(lock_path_for(&path)).unwrap();
+        let result = contender.lock_exclusive(Duration::from_millis(150));
+
+        assert!(result.is_err());
+        let err = result.err().expect("already checked is_err").to_string();
+        assert!(
+            err.contains("Another wallet process is running"),
+            "error should explain the contention, got: {err}"
+        );
+    }
+
+    #[test]
+    fn with_exclusive_runs_the_closure_and_releases() {
+        let path = test_path("with_exclusive_runs_the_closure_and_releases");
+        let _ = std::fs::remove_file(lock_path_for(&path));
+
+        let ran = with_exclusive(&path, Duration::from_secs(1), || Ok(42)).unwrap();
+        assert_eq!(ran, 42);
+
+        // Released afterwards, so a second call shouldn't block or time out.
+        let ran_again = with_exclusive(&path, Duration::from_millis(50), || Ok(7)).unwrap();
+        assert_eq!(ran_again, 7);
+    }
+}
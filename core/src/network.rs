@@ -1,4 +1,7 @@
 /// Thin wrapper around the SDK's GraphQL client for network operations.
+use std::collections::BTreeMap;
+use std::time::Duration;
+
 use anyhow::{Context, Result, bail};
 use iota_sdk::crypto::ed25519::Ed25519PrivateKey;
 use iota_sdk::crypto::IotaSigner;
@@ -7,45 +10,105 @@ use iota_sdk::graphql_client::pagination::PaginationFilter;
 use iota_sdk::graphql_client::query_types::TransactionsFilter;
 use iota_sdk::graphql_client::Client;
 use iota_sdk::transaction_builder::TransactionBuilder;
-use iota_sdk::types::Address;
+use iota_sdk::types::{Address, Digest, ObjectId, Transaction};
+use rand::Rng;
 
+use crate::display::IotaAmount;
 use crate::wallet::{Network, NetworkConfig};
 
+#[derive(Clone)]
 pub struct NetworkClient {
     client: Client,
     network: Network,
+    retry: RetryConfig,
+    quorum: Option<Quorum>,
+}
+
+/// Built clients for quorum mode: one per endpoint in [`QuorumConfig`], plus
+/// how many of them must agree before a read is accepted.
+#[derive(Clone)]
+struct Quorum {
+    clients: Vec<Client>,
+    threshold: usize,
 }
 
 impl NetworkClient {
     pub fn new(config: &NetworkConfig) -> Result<Self> {
+        let mut quorum = None;
         let client = match &config.network {
             Network::Testnet => Client::new_testnet(),
             Network::Mainnet => Client::new_mainnet(),
             Network::Devnet => Client::new_devnet(),
             Network::Custom => {
-                let url = config
-                    .custom_url
-                    .as_ref()
-                    .ok_or_else(|| anyhow::anyhow!("Custom network requires a node URL"))?;
-                Client::new(url)
-                    .context("Failed to create client with custom URL")?
+                if let Some(quorum_cfg) = &config.quorum {
+                    if quorum_cfg.endpoints.is_empty() {
+                        bail!("Quorum mode requires at least one endpoint");
+                    }
+                    let clients = quorum_cfg
+                        .endpoints
+                        .iter()
+                        .map(|url| {
+                            Client::new(url)
+                                .with_context(|| format!("Failed to create quorum client for {url}"))
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    let primary = clients[0].clone();
+                    quorum = Some(Quorum {
+                        clients,
+                        threshold: quorum_cfg.threshold.max(1),
+                    });
+                    primary
+                } else {
+                    let url = config
+                        .custom_url
+                        .as_ref()
+                        .ok_or_else(|| anyhow::anyhow!("Custom network requires a node URL"))?;
+                    Client::new(url)
+                        .context("Failed to create client with custom URL")?
+                }
             }
         };
 
         Ok(Self {
             client,
             network: config.network,
+            retry: config.retry,
+            quorum,
         })
     }
 
     /// Query the IOTA balance for an address (in nanos).
+    ///
+    /// In quorum mode, queries every configured endpoint and only returns a
+    /// value once at least `threshold` of them agree; see [`QuorumConfig`].
     pub async fn balance(&self, address: &Address) -> Result<u64> {
-        let balance = self
-            .client
-            .balance(*address, None)
+        let Some(quorum) = &self.quorum else {
+            let balance = self
+                .with_retry(|| async { self.client.balance(*address, None).await })
+                .await
+                .context("Failed to query balance")?;
+            return Ok(balance.unwrap_or(0));
+        };
+
+        let attempts = quorum
+            .clients
+            .iter()
+            .map(|client| run_with_retry(&self.retry, || async { client.balance(*address, None).await }));
+        let results: Vec<u64> = futures::future::join_all(attempts)
             .await
-            .context("Failed to query balance")?;
-        Ok(balance.unwrap_or(0))
+            .into_iter()
+            .filter_map(|r| r.ok())
+            .map(|balance| balance.unwrap_or(0))
+            .collect();
+
+        quorum_agree(&results, quorum.threshold).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Quorum not reached for balance: needed {} of {} endpoint(s) to agree (got {} reachable)",
+                quorum.threshold,
+                quorum.clients.len(),
+                results.len(),
+            )
+        })
     }
 
     /// Send IOTA from the signer's address to a recipient.
@@ -65,10 +128,313 @@ impl NetworkClient {
             .await
             .context("Failed to build transaction")?;
 
-        // Dry run first to catch errors before spending gas
+        self.sign_and_execute(&tx, private_key).await
+    }
+
+    /// Send IOTA to multiple recipients in a single transaction: one
+    /// `send_iota` call per payment against a shared `TransactionBuilder`,
+    /// and a single signature and submission. The transaction is dry-run
+    /// twice: once for the funds check against the sender's balance and
+    /// again by [`Self::sign_and_execute`] before signing. Far cheaper
+    /// in gas than `payments.len()` separate transfers — the common
+    /// payroll/airdrop case.
+    pub async fn send_iota_batch(
+        &self,
+        private_key: &Ed25519PrivateKey,
+        sender: &Address,
+        payments: &[(Address, u64)],
+    ) -> Result<TransferResult> {
+        if payments.is_empty() {
+            bail!("send_iota_batch requires at least one payment");
+        }
+
+        let mut builder = TransactionBuilder::new(*sender).with_client(&self.client);
+        for (recipient, amount) in payments {
+            builder.send_iota(*recipient, *amount);
+        }
+
+        let tx = builder
+            .finish()
+            .await
+            .context("Failed to build batch transaction")?;
+
+        let total: u64 = payments.iter().map(|(_, amount)| *amount).sum();
+        let preflight = self.preflight(sender, &tx, total).await?;
+        if let Some(shortfall) = preflight.shortfall {
+            bail!(
+                "Insufficient funds for batch transfer: need {shortfall} more IOTA to cover \
+                 {} payment(s) plus gas (requires {}, gas budget {}).",
+                payments.len(),
+                preflight.total_required,
+                preflight.gas_budget,
+            );
+        }
+
+        self.sign_and_execute(&tx, private_key).await
+    }
+
+    /// Stake `amount` nanos to `validator`.
+    pub async fn stake_iota(
+        &self,
+        private_key: &Ed25519PrivateKey,
+        sender: &Address,
+        validator: Address,
+        amount: u64,
+    ) -> Result<TransferResult> {
+        let mut builder = TransactionBuilder::new(*sender).with_client(&self.client);
+        builder.stake(amount, validator);
+
+        let tx = builder
+            .finish()
+            .await
+            .context("Failed to build stake transaction")?;
+
+        self.sign_and_execute(&tx, private_key).await
+    }
+
+    /// Unstake a previously staked IOTA object.
+    pub async fn unstake_iota(
+        &self,
+        private_key: &Ed25519PrivateKey,
+        sender: &Address,
+        staked_object_id: ObjectId,
+    ) -> Result<TransferResult> {
+        let mut builder = TransactionBuilder::new(*sender).with_client(&self.client);
+        builder.unstake(staked_object_id);
+
+        let tx = builder
+            .finish()
+            .await
+            .context("Failed to build unstake transaction")?;
+
+        self.sign_and_execute(&tx, private_key).await
+    }
+
+    /// Send the sender's entire balance (minus estimated gas) to `recipient`.
+    /// Returns the transfer result and the amount actually swept, in nanos.
+    pub async fn sweep_all(
+        &self,
+        private_key: &Ed25519PrivateKey,
+        sender: &Address,
+        recipient: Address,
+    ) -> Result<(TransferResult, u64)> {
+        let balance = self.balance(sender).await?;
+        if balance == 0 {
+            bail!("No balance to sweep.");
+        }
+
+        // Estimate gas against the full balance first, then send balance
+        // minus gas so the transaction doesn't try to spend more than it has.
+        let preflight = self.estimate_transfer(sender, recipient, balance).await?;
+        let sweep_amount = balance
+            .checked_sub(preflight.gas_budget.as_nanos())
+            .filter(|&amount| amount > 0)
+            .ok_or_else(|| anyhow::anyhow!("Balance is too small to cover gas"))?;
+
+        let result = self
+            .send_iota(private_key, sender, recipient, sweep_amount)
+            .await?;
+        Ok((result, sweep_amount))
+    }
+
+    /// Query all StakedIota objects owned by `address`, including estimated
+    /// rewards computed by the network.
+    pub async fn get_stakes(&self, address: &Address) -> Result<Vec<StakedIotaSummary>> {
+        let query = serde_json::json!({
+            "query": r#"query ($owner: IotaAddress!) {
+                address(address: $owner) {
+                    stakedIotas {
+                        nodes {
+                            address
+                            stakeStatus
+                            activatedEpoch { epochId }
+                            poolId
+                            principal
+                            estimatedReward
+                        }
+                    }
+                }
+            }"#,
+            "variables": {
+                "owner": address.to_string()
+            }
+        });
+
+        let data = self
+            .execute_query(query, "Failed to query staked objects")
+            .await?;
+        let nodes = data
+            .get("address")
+            .and_then(|a| a.get("stakedIotas"))
+            .and_then(|s| s.get("nodes"))
+            .and_then(|n| n.as_array())
+            .map(|v| v.as_slice())
+            .unwrap_or(&[]);
+
+        let mut stakes = Vec::new();
+        for node in nodes {
+            let object_id = json_object_id(node, "address");
+            let pool_id = json_object_id(node, "poolId");
+            let principal = json_str_field::<u64>(node, "principal").unwrap_or(0);
+            let stake_activation_epoch = node
+                .get("activatedEpoch")
+                .and_then(|v| v.get("epochId"))
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0);
+            let estimated_reward = json_str_field::<u64>(node, "estimatedReward");
+            let status = match node.get("stakeStatus").and_then(|v| v.as_str()) {
+                Some("ACTIVE") => StakeStatus::Active,
+                Some("PENDING") => StakeStatus::Pending,
+                _ => StakeStatus::Unstaked,
+            };
+
+            if let (Some(object_id), Some(pool_id)) = (object_id, pool_id) {
+                stakes.push(StakedIotaSummary {
+                    object_id,
+                    pool_id,
+                    principal,
+                    stake_activation_epoch,
+                    estimated_reward,
+                    status,
+                });
+            }
+        }
+
+        Ok(stakes)
+    }
+
+    /// Send `amount` base units of a non-IOTA coin type from the signer's
+    /// address to a recipient. Unlike [`Self::send_iota`], the amount is
+    /// always in the token's own base units (whatever [`Self::coin_decimals`]
+    /// reports for `coin_type`), not assumed to be 9-decimal nanos.
+    pub async fn send_token(
+        &self,
+        private_key: &Ed25519PrivateKey,
+        sender: &Address,
+        recipient: Address,
+        coin_type: &str,
+        amount: u64,
+    ) -> Result<TransferResult> {
+        let mut builder = TransactionBuilder::new(*sender).with_client(&self.client);
+        builder.send_coin(coin_type, recipient, amount);
+
+        let tx = builder
+            .finish()
+            .await
+            .context("Failed to build token transfer transaction")?;
+
+        self.sign_and_execute(&tx, private_key).await
+    }
+
+    /// List every coin type held by `address`, with its symbol (if the node
+    /// has metadata for it), the coin's own decimals, and the raw base-unit
+    /// amount held.
+    pub async fn get_token_balances(&self, address: &Address) -> Result<Vec<TokenBalance>> {
+        let query = serde_json::json!({
+            "query": r#"query ($owner: IotaAddress!) {
+                address(address: $owner) {
+                    balances {
+                        nodes {
+                            coinType { repr }
+                            totalBalance
+                        }
+                    }
+                }
+            }"#,
+            "variables": {
+                "owner": address.to_string()
+            }
+        });
+
+        let data = self
+            .execute_query(query, "Failed to query token balances")
+            .await?;
+        let nodes = data
+            .get("address")
+            .and_then(|a| a.get("balances"))
+            .and_then(|b| b.get("nodes"))
+            .and_then(|n| n.as_array())
+            .map(|v| v.as_slice())
+            .unwrap_or(&[]);
+
+        let mut balances = Vec::with_capacity(nodes.len());
+        for node in nodes {
+            let coin_type = node
+                .get("coinType")
+                .and_then(|c| c.get("repr"))
+                .and_then(|v| v.as_str())
+                .map(str::to_string);
+            let Some(coin_type) = coin_type else {
+                continue;
+            };
+            let amount = json_str_field::<u64>(node, "totalBalance").unwrap_or(0);
+            let metadata = self.coin_metadata(&coin_type).await.unwrap_or_default();
+
+            balances.push(TokenBalance {
+                coin_type,
+                symbol: metadata.symbol,
+                decimals: metadata.decimals,
+                amount,
+            });
+        }
+
+        Ok(balances)
+    }
+
+    /// Look up how many decimal places `coin_type` uses, so amounts typed on
+    /// the command line can be parsed against the token's own denomination
+    /// instead of assuming IOTA's 9 decimals.
+    pub async fn coin_decimals(&self, coin_type: &str) -> Result<u8> {
+        Ok(self.coin_metadata(coin_type).await?.decimals)
+    }
+
+    /// Fetch a coin type's decimals and symbol, if the node has metadata
+    /// registered for it.
+    async fn coin_metadata(&self, coin_type: &str) -> Result<CoinMetadata> {
+        let query = serde_json::json!({
+            "query": r#"query ($type: String!) {
+                coinMetadata(coinType: $type) {
+                    decimals
+                    symbol
+                }
+            }"#,
+            "variables": {
+                "type": coin_type
+            }
+        });
+
+        let data = self
+            .execute_query(query, "Failed to query coin metadata")
+            .await?;
+        let metadata = data.get("coinMetadata");
+
+        Ok(CoinMetadata {
+            decimals: metadata
+                .and_then(|m| m.get("decimals"))
+                .and_then(|v| v.as_u64())
+                .map(|d| d as u8)
+                .unwrap_or(0),
+            symbol: metadata
+                .and_then(|m| m.get("symbol"))
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+        })
+    }
+
+    /// Sign `tx` with `private_key`, dry-running first to catch errors
+    /// before spending gas, and submit it.
+    ///
+    /// In quorum mode, the signed transaction is broadcast to every
+    /// configured endpoint concurrently; unlike reads, a write only needs
+    /// one endpoint to accept it for the transaction to land on-chain.
+    async fn sign_and_execute(
+        &self,
+        tx: &Transaction,
+        private_key: &Ed25519PrivateKey,
+    ) -> Result<TransferResult> {
         let dry_run = self
             .client
-            .dry_run_tx(&tx, false)
+            .dry_run_tx(tx, false)
             .await
             .context("Dry run failed")?;
         if let Some(err) = dry_run.error {
@@ -76,14 +442,25 @@ impl NetworkClient {
         }
 
         let signature = private_key
-            .sign_transaction(&tx)
+            .sign_transaction(tx)
             .map_err(|e| anyhow::anyhow!("Failed to sign transaction: {e}"))?;
 
-        let effects = self
-            .client
-            .execute_tx(&[signature], &tx, None)
-            .await
-            .context("Failed to execute transaction")?;
+        let effects = match &self.quorum {
+            None => self
+                .with_retry(|| self.client.execute_tx(&[signature], tx, None))
+                .await
+                .context("Failed to execute transaction")?,
+            Some(quorum) => {
+                let attempts = quorum.clients.iter().map(|client| {
+                    run_with_retry(&self.retry, || client.execute_tx(&[signature.clone()], tx, None))
+                });
+                futures::future::join_all(attempts)
+                    .await
+                    .into_iter()
+                    .find_map(|r| r.ok())
+                    .ok_or_else(|| anyhow::anyhow!("Transaction was rejected by every quorum endpoint"))?
+            }
+        };
 
         let digest = effects.digest().to_string();
         let status = format!("{:?}", effects.status());
@@ -91,6 +468,69 @@ impl NetworkClient {
         Ok(TransferResult { digest, status })
     }
 
+    /// Run a raw GraphQL query against the node, returning the `data` object.
+    /// Transient failures (rate limits, timeouts, connection resets) are
+    /// retried with backoff; see [`Self::with_retry`].
+    async fn execute_query(&self, query: serde_json::Value, err_ctx: &str) -> Result<serde_json::Value> {
+        self.with_retry(|| self.client.execute_graphql(query.clone()))
+            .await
+            .with_context(|| err_ctx.to_string())
+    }
+
+    /// Build (but don't sign or submit) a transfer transaction and check
+    /// whether `sender`'s current balance covers the amount plus gas.
+    ///
+    /// Lets callers warn the user before they sign instead of discovering
+    /// an insufficient-funds error after the transaction has already been
+    /// dry-run and rejected by validation.
+    pub async fn estimate_transfer(
+        &self,
+        sender: &Address,
+        recipient: Address,
+        amount: u64,
+    ) -> Result<PreflightResult> {
+        let mut builder = TransactionBuilder::new(*sender).with_client(&self.client);
+        builder.send_iota(recipient, amount);
+
+        let tx = builder
+            .finish()
+            .await
+            .context("Failed to build transaction")?;
+
+        self.preflight(sender, &tx, amount).await
+    }
+
+    /// Dry-run `tx` to determine the gas it would consume, then compare
+    /// `amount + gas_budget` against the sender's current balance.
+    async fn preflight(
+        &self,
+        sender: &Address,
+        tx: &iota_sdk::types::Transaction,
+        amount: u64,
+    ) -> Result<PreflightResult> {
+        let dry_run = self
+            .client
+            .dry_run_tx(tx, false)
+            .await
+            .context("Dry run failed")?;
+        if let Some(err) = dry_run.error {
+            bail!("Transaction would fail: {err}");
+        }
+
+        let net_gas = dry_run.effects.gas_summary().net_gas_usage();
+        let gas_budget = u64::try_from(net_gas).unwrap_or(0);
+        let total_required = amount.saturating_add(gas_budget);
+
+        let balance = self.balance(sender).await?;
+        let shortfall = total_required.checked_sub(balance).filter(|&s| s > 0);
+
+        Ok(PreflightResult {
+            total_required: IotaAmount::from_nanos(total_required),
+            gas_budget: IotaAmount::from_nanos(gas_budget),
+            shortfall: shortfall.map(IotaAmount::from_nanos),
+        })
+    }
+
     /// Request tokens from the faucet (testnet/devnet only).
     pub async fn faucet(&self, address: &Address) -> Result<()> {
         match &self.network {
@@ -164,8 +604,10 @@ impl NetworkClient {
         filter: TransactionsFilter,
     ) -> Result<Vec<TransactionSummary>> {
         let page = self
-            .client
-            .transactions(Some(filter), PaginationFilter::default())
+            .with_retry(|| {
+                self.client
+                    .transactions(Some(filter.clone()), PaginationFilter::default())
+            })
             .await
             .context("Failed to query transactions")?;
 
@@ -195,6 +637,507 @@ impl NetworkClient {
     pub fn client(&self) -> &Client {
         &self.client
     }
+
+    /// Look up a transaction by its digest, returning its current status,
+    /// sender, and gas fee.
+    ///
+    /// In quorum mode, queries every configured endpoint and only returns a
+    /// value once at least `threshold` of them agree on the full summary
+    /// (effectively digest and effects equality); see [`QuorumConfig`].
+    pub async fn transaction_details(&self, digest: &Digest) -> Result<TransactionDetailsSummary> {
+        let Some(quorum) = &self.quorum else {
+            return self.transaction_details_from(&self.client, digest).await;
+        };
+
+        let attempts = quorum
+            .clients
+            .iter()
+            .map(|client| self.transaction_details_from(client, digest));
+        let results: Vec<TransactionDetailsSummary> = futures::future::join_all(attempts)
+            .await
+            .into_iter()
+            .filter_map(|r| r.ok())
+            .collect();
+
+        quorum_agree(&results, quorum.threshold).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Quorum not reached for transaction {digest}: needed {} of {} endpoint(s) to agree (got {} reachable)",
+                quorum.threshold,
+                quorum.clients.len(),
+                results.len(),
+            )
+        })
+    }
+
+    /// Fetch and summarize `digest` from a specific endpoint; factored out of
+    /// [`Self::transaction_details`] so quorum mode can run it against every
+    /// configured client.
+    async fn transaction_details_from(
+        &self,
+        client: &Client,
+        digest: &Digest,
+    ) -> Result<TransactionDetailsSummary> {
+        let data_effects = run_with_retry(&self.retry, || client.transaction_data_effects(*digest))
+            .await
+            .context("Failed to query transaction")?
+            .ok_or_else(|| anyhow::anyhow!("Transaction not found: {digest}"))?;
+
+        let tx = &data_effects.tx.transaction;
+        let effects = &data_effects.effects;
+
+        let sender = match tx {
+            Transaction::V1(v1) => v1.sender.to_string(),
+        };
+
+        let status = format!("{:?}", effects.status());
+        let gas = effects.gas_summary();
+        let net = gas.net_gas_usage();
+        let fee = u64::try_from(net).ok().filter(|&f| f > 0);
+        let checkpoint = effects.checkpoint();
+
+        // Reconstruct the actual balance movement from effects rather than
+        // assuming a single-recipient `TransferObjects` command, so this
+        // covers multi-recipient transfers, split-coin change, and
+        // staking/other transaction kinds too. Reuses the gas fee and
+        // sender already fetched above instead of querying them again.
+        let gas_fee = fee.unwrap_or(0);
+        let deltas = self.object_balance_deltas(digest, &sender, gas_fee).await?;
+        let balance_changes = TransactionBalanceChanges { deltas, gas_fee };
+        let (recipient, amount) = single_recipient(&balance_changes, &sender);
+
+        Ok(TransactionDetailsSummary {
+            digest: digest.to_string(),
+            status,
+            kind: "transaction".to_string(),
+            sender: Some(sender),
+            recipient,
+            amount,
+            fee,
+            checkpoint,
+            balance_changes,
+        })
+    }
+
+    /// Reconstruct the true net IOTA balance change per address for a
+    /// transaction, rather than assuming a single-recipient `TransferObjects`
+    /// command: handles multi-recipient transfers, split-coin change, and
+    /// staking/other transaction kinds, none of which fit in
+    /// [`TransactionDetailsSummary::recipient`]/`amount`.
+    ///
+    /// In quorum mode, queries every configured endpoint and only returns a
+    /// value once at least `threshold` of them agree; see [`QuorumConfig`].
+    /// Only the transaction lookup and gas fee come from each endpoint; the
+    /// per-address deltas are read through the primary client for all of them.
+    pub async fn balance_changes(&self, digest: &Digest) -> Result<TransactionBalanceChanges> {
+        let Some(quorum) = &self.quorum else {
+            return self.balance_changes_from(&self.client, digest).await;
+        };
+
+        let attempts = quorum
+            .clients
+            .iter()
+            .map(|client| self.balance_changes_from(client, digest));
+        let results: Vec<TransactionBalanceChanges> = futures::future::join_all(attempts)
+            .await
+            .into_iter()
+            .filter_map(|r| r.ok())
+            .collect();
+
+        quorum_agree(&results, quorum.threshold).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Quorum not reached for balance changes of transaction {digest}: needed {} of {} endpoint(s) to agree (got {} reachable)",
+                quorum.threshold,
+                quorum.clients.len(),
+                results.len(),
+            )
+        })
+    }
+
+    /// Fetch `digest`'s effects and gas fee from a specific endpoint and
+    /// diff its object changes; factored out of [`Self::balance_changes`] so
+    /// quorum mode can run it against every configured client. The object
+    /// changes themselves are always queried through the primary client
+    /// (see [`Self::object_balance_deltas`]), not through `client`.
+    async fn balance_changes_from(
+        &self,
+        client: &Client,
+        digest: &Digest,
+    ) -> Result<TransactionBalanceChanges> {
+        let data_effects = run_with_retry(&self.retry, || client.transaction_data_effects(*digest))
+            .await
+            .context("Failed to query transaction")?
+            .ok_or_else(|| anyhow::anyhow!("Transaction not found: {digest}"))?;
+
+        let tx = &data_effects.tx.transaction;
+        let sender = match tx {
+            Transaction::V1(v1) => v1.sender.to_string(),
+        };
+        let net_gas = data_effects.effects.gas_summary().net_gas_usage();
+        let gas_fee = u64::try_from(net_gas).unwrap_or(0);
+
+        let deltas = self.object_balance_deltas(digest, &sender, gas_fee).await?;
+        Ok(TransactionBalanceChanges { deltas, gas_fee })
+    }
+
+    /// Query and diff `digest`'s `objectChanges`, grouping coin balance
+    /// deltas by owning address and netting the sender's gas payment back
+    /// out of their total. Shared by [`Self::balance_changes_from`] and
+    /// [`Self::transaction_details_from`] so the latter doesn't have to
+    /// re-fetch transaction effects it already has.
+    async fn object_balance_deltas(
+        &self,
+        digest: &Digest,
+        sender: &str,
+        gas_fee: u64,
+    ) -> Result<BTreeMap<String, i64>> {
+        let query = serde_json::json!({
+            "query": r#"query ($digest: String!) {
+                transactionBlock(digest: $digest) {
+                    effects {
+                        objectChanges {
+                            nodes {
+                                inputState {
+                                    asMoveObject { contents { json } }
+                                    owner { asAddressOwner { owner { address } } }
+                                }
+                                outputState {
+                                    asMoveObject { contents { json } }
+                                    owner { asAddressOwner { owner { address } } }
+                                }
+                            }
+                        }
+                    }
+                }
+            }"#,
+            "variables": {
+                "digest": digest.to_string()
+            }
+        });
+
+        let data = self
+            .execute_query(query, "Failed to query object changes")
+            .await?;
+        let nodes = data
+            .get("transactionBlock")
+            .and_then(|t| t.get("effects"))
+            .and_then(|e| e.get("objectChanges"))
+            .and_then(|c| c.get("nodes"))
+            .and_then(|n| n.as_array())
+            .map(|v| v.as_slice())
+            .unwrap_or(&[]);
+
+        let mut deltas: BTreeMap<String, i64> = BTreeMap::new();
+        for node in nodes {
+            let pre = coin_balance_and_owner(node.get("inputState"));
+            let post = coin_balance_and_owner(node.get("outputState"));
+
+            // Only coins carry a balance; other Move objects (staked IOTA,
+            // package metadata, ...) don't move IOTA and are skipped.
+            if pre.is_none() && post.is_none() {
+                continue;
+            }
+            let pre_balance = pre.as_ref().map(|(balance, _)| *balance).unwrap_or(0);
+            let post_balance = post.as_ref().map(|(balance, _)| *balance).unwrap_or(0);
+            // Mutated/transferred objects keep the post-state owner; deleted
+            // objects (post is absent) last belonged to the pre-state owner.
+            let owner = post
+                .as_ref()
+                .map(|(_, owner)| owner.clone())
+                .or_else(|| pre.as_ref().map(|(_, owner)| owner.clone()));
+            let Some(owner) = owner else { continue };
+
+            let delta = post_balance as i64 - pre_balance as i64;
+            if delta != 0 {
+                *deltas.entry(owner).or_insert(0) += delta;
+            }
+        }
+
+        // The sender's gas coin was debited to pay for this transaction;
+        // pull that out of their delta so it only reflects what they
+        // actually transferred, with the fee reported separately.
+        if let Some(sender_delta) = deltas.get_mut(sender) {
+            *sender_delta += gas_fee as i64;
+            if *sender_delta == 0 {
+                deltas.remove(sender);
+            }
+        }
+
+        Ok(deltas)
+    }
+
+    /// The sequence number of the most recently produced checkpoint, used to
+    /// measure how many checkpoints have landed on top of a transaction's own
+    /// checkpoint (see [`Self::send_iota_and_wait`]).
+    async fn latest_checkpoint(&self) -> Result<u64> {
+        let query = serde_json::json!({
+            "query": r#"query {
+                checkpoint {
+                    sequenceNumber
+                }
+            }"#
+        });
+
+        let data = self
+            .execute_query(query, "Failed to query latest checkpoint")
+            .await?;
+        data.get("checkpoint")
+            .and_then(|c| c.get("sequenceNumber"))
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| anyhow::anyhow!("Node did not report a latest checkpoint"))
+    }
+
+    /// Send IOTA and block until the transaction has landed in a checkpoint
+    /// and `config.confirmations` further checkpoints have been produced on
+    /// top of it, modeled on ethers-rs's `PendingTransaction`.
+    ///
+    /// Returns the final transaction details (not the best-effort result
+    /// `send_iota` gets back from execution) once confirmed, or an error if
+    /// `config.timeout` elapses first or the transaction lands with a
+    /// failure status.
+    pub async fn send_iota_and_wait(
+        &self,
+        private_key: &Ed25519PrivateKey,
+        sender: &Address,
+        recipient: Address,
+        amount: u64,
+        config: &ConfirmationConfig,
+    ) -> Result<TransactionDetailsSummary> {
+        let result = self.send_iota(private_key, sender, recipient, amount).await?;
+        self.wait_for_confirmation(&result.digest, config).await
+    }
+
+    /// Stake IOTA and block until confirmed. See [`Self::send_iota_and_wait`].
+    pub async fn stake_iota_and_wait(
+        &self,
+        private_key: &Ed25519PrivateKey,
+        sender: &Address,
+        validator: Address,
+        amount: u64,
+        config: &ConfirmationConfig,
+    ) -> Result<TransactionDetailsSummary> {
+        let result = self.stake_iota(private_key, sender, validator, amount).await?;
+        self.wait_for_confirmation(&result.digest, config).await
+    }
+
+    /// Poll `transaction_details(digest)` every `config.poll_interval` until
+    /// it has landed with `config.confirmations` checkpoints built on top of
+    /// it, a definitive failure status is seen, or `config.timeout` elapses.
+    ///
+    /// "Not found yet" (the node hasn't indexed it) and "found but not yet
+    /// checkpointed enough times" both keep the loop waiting; a failure
+    /// status returns immediately rather than waiting out the timeout.
+    async fn wait_for_confirmation(
+        &self,
+        digest: &str,
+        config: &ConfirmationConfig,
+    ) -> Result<TransactionDetailsSummary> {
+        let digest: Digest = digest
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid digest '{digest}': {e}"))?;
+        let deadline = tokio::time::Instant::now() + config.timeout;
+
+        loop {
+            if let Ok(details) = self.transaction_details(&digest).await {
+                if is_failure_status(&details.status) {
+                    bail!("Transaction failed: {}", details.status);
+                }
+
+                if let Some(checkpoint) = details.checkpoint {
+                    let latest = self.latest_checkpoint().await.unwrap_or(checkpoint);
+                    let confirmations = latest.saturating_sub(checkpoint);
+                    if confirmations >= config.confirmations {
+                        return Ok(details);
+                    }
+                }
+            }
+            // Not found yet, or found but awaiting more confirmations: keep polling.
+
+            if tokio::time::Instant::now() >= deadline {
+                bail!(
+                    "Timed out after {:?} waiting for {digest} to reach {} confirmation(s).",
+                    config.timeout,
+                    config.confirmations,
+                );
+            }
+
+            tokio::time::sleep(config.poll_interval).await;
+        }
+    }
+
+    /// Run `op` against the primary client, retrying with truncated
+    /// exponential backoff plus jitter on transient errors up to
+    /// `self.retry.max_retries` times. See [`run_with_retry`].
+    async fn with_retry<T, E, F, Fut>(&self, op: F) -> Result<T>
+    where
+        E: std::error::Error + Send + Sync + 'static,
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = std::result::Result<T, E>>,
+    {
+        run_with_retry(&self.retry, op).await
+    }
+}
+
+/// Run `op`, retrying with truncated exponential backoff plus jitter on
+/// transient errors (rate limits, connection resets, gateway timeouts) up to
+/// `retry.max_retries` times. Fatal errors (bad requests, a transaction that
+/// would fail) propagate on the first attempt.
+///
+/// Free function (rather than a method) so quorum mode can apply it per
+/// client in [`NetworkClient::balance`], [`NetworkClient::transaction_details_from`],
+/// and [`NetworkClient::sign_and_execute`].
+async fn run_with_retry<T, E, F, Fut>(retry: &RetryConfig, op: F) -> Result<T>
+where
+    E: std::error::Error + Send + Sync + 'static,
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<T, E>>,
+{
+    let mut attempt: u32 = 0;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                let err = anyhow::Error::new(err);
+                if attempt >= retry.max_retries || !is_retryable(&err) {
+                    return Err(err);
+                }
+                let delay = backoff_delay(retry, attempt, is_rate_limited(&err));
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Pick the value that at least `threshold` of `results` agree on (by
+/// equality), or `None` if no value reaches that threshold. Used by quorum
+/// reads to accept a value only once enough endpoints agree; see
+/// [`QuorumConfig`].
+fn quorum_agree<T: PartialEq + Clone>(results: &[T], threshold: usize) -> Option<T> {
+    results
+        .iter()
+        .find(|candidate| results.iter().filter(|v| v == candidate).count() >= threshold)
+        .cloned()
+}
+
+/// Reduce a reconstructed [`TransactionBalanceChanges`] to the single
+/// `(recipient, amount)` pair [`TransactionDetailsSummary`] shows, when
+/// there's exactly one non-sender address with a positive net credit.
+/// Multi-recipient transfers, split-coin change with no other recipient,
+/// and non-transfer kinds all fall through to `(None, None)` — the full
+/// per-address breakdown is still available via `balance_changes`.
+fn single_recipient(changes: &TransactionBalanceChanges, sender: &str) -> (Option<String>, Option<IotaAmount>) {
+    let mut credits = changes
+        .deltas
+        .iter()
+        .filter(|(address, delta)| address.as_str() != sender && **delta > 0);
+
+    match (credits.next(), credits.next()) {
+        (Some((address, delta)), None) => {
+            (Some(address.clone()), Some(IotaAmount::from_nanos(*delta as u64)))
+        }
+        _ => (None, None),
+    }
+}
+
+/// Whether `err`'s message looks like a transient failure worth retrying
+/// (rate limit, connection error, gateway timeout) as opposed to a fatal one
+/// (bad request, a transaction that would fail) that should propagate
+/// immediately. Classified by message content, the same way
+/// [`crate::signer::ledger::map_device_error`] classifies device errors,
+/// since the SDK doesn't expose a richer error enum to match on.
+fn is_retryable(err: &anyhow::Error) -> bool {
+    let msg = err.to_string().to_lowercase();
+    if msg.contains("would fail") || msg.contains("bad request") || msg.contains("invalid") {
+        return false;
+    }
+    is_rate_limited(err)
+        || msg.contains("timeout")
+        || msg.contains("timed out")
+        || msg.contains("connection reset")
+        || msg.contains("connection refused")
+        || msg.contains("gateway")
+}
+
+/// Whether `err` specifically signals a rate limit, so the backoff can be
+/// stretched further than a generic transient failure.
+fn is_rate_limited(err: &anyhow::Error) -> bool {
+    let msg = err.to_string().to_lowercase();
+    msg.contains("429") || msg.contains("rate limit") || msg.contains("too many requests")
+}
+
+/// `min(base * 2^attempt, cap)`, quadrupled when `rate_limited` to back off
+/// harder for rate limits specifically, plus up to 25% jitter so concurrent
+/// callers don't retry in lockstep.
+fn backoff_delay(retry: &RetryConfig, attempt: u32, rate_limited: bool) -> Duration {
+    let base = if rate_limited {
+        retry.base_delay.saturating_mul(4)
+    } else {
+        retry.base_delay
+    };
+    let scaled = base
+        .checked_mul(2u32.saturating_pow(attempt))
+        .unwrap_or(retry.max_delay);
+    let capped = scaled.min(retry.max_delay);
+    let jitter = rand::thread_rng().gen_range(0.0..0.25);
+    capped.mul_f64(1.0 + jitter)
+}
+
+/// Configuration for [`NetworkClient::with_retry`]: the backoff base delay,
+/// its cap, and how many times to retry a transient failure before giving up.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub max_retries: u32,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+            max_retries: 5,
+        }
+    }
+}
+
+/// Configuration for quorum mode on `Network::Custom`, modeled on
+/// ethers-rs's `QuorumProvider`: query every endpoint in `endpoints` and
+/// only accept a read once at least `threshold` of them agree, so pointing
+/// at third-party nodes doesn't mean trusting any single one of them.
+/// Writes are instead broadcast to every endpoint and succeed if any one
+/// accepts; see [`NetworkClient::sign_and_execute`].
+#[derive(Debug, Clone, Default)]
+pub struct QuorumConfig {
+    pub endpoints: Vec<String>,
+    pub threshold: usize,
+}
+
+/// Whether a transaction effects status string represents a definitive
+/// failure (as opposed to still pending or succeeded).
+fn is_failure_status(status: &str) -> bool {
+    status.to_lowercase().contains("fail")
+}
+
+/// Configuration for [`NetworkClient::send_iota_and_wait`] and
+/// [`NetworkClient::stake_iota_and_wait`]: how often to poll, how long to
+/// wait before giving up, and how many checkpoints must be built on top of
+/// the transaction's own checkpoint before it's considered confirmed.
+#[derive(Debug, Clone, Copy)]
+pub struct ConfirmationConfig {
+    pub poll_interval: std::time::Duration,
+    pub timeout: std::time::Duration,
+    pub confirmations: u64,
+}
+
+impl Default for ConfirmationConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: std::time::Duration::from_millis(500),
+            timeout: std::time::Duration::from_secs(60),
+            confirmations: 1,
+        }
+    }
 }
 
 pub struct TransferResult {
@@ -202,6 +1145,151 @@ pub struct TransferResult {
     pub status: String,
 }
 
+/// Status, participants, and fee for a single transaction, as returned by
+/// [`NetworkClient::transaction_details`].
+///
+/// `recipient`/`amount` are populated from `balance_changes` when exactly
+/// one non-sender address received a net credit (the common single-payment
+/// case); for multi-recipient transfers, splits, or non-transfer kinds they
+/// are `None` and the full picture lives in `balance_changes`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionDetailsSummary {
+    pub digest: String,
+    pub status: String,
+    pub kind: String,
+    pub sender: Option<String>,
+    pub recipient: Option<String>,
+    pub amount: Option<IotaAmount>,
+    pub fee: Option<u64>,
+    /// Sequence number of the checkpoint this transaction landed in, if any.
+    pub checkpoint: Option<u64>,
+    /// Per-address balance deltas reconstructed from effects; see
+    /// [`TransactionBalanceChanges`].
+    pub balance_changes: TransactionBalanceChanges,
+}
+
+/// Net per-address IOTA balance change for a transaction, as returned by
+/// [`NetworkClient::balance_changes`].
+///
+/// Reconstructed by diffing every coin object the transaction touched
+/// (created, mutated, or deleted) rather than reading a single
+/// `TransferObjects` command, so it covers multi-recipient transfers,
+/// split-coin change, and staking/other transaction kinds. A self-transfer
+/// nets to near-zero; a pure Move call with no coin movement yields an
+/// empty `deltas` map and only `gas_fee`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TransactionBalanceChanges {
+    /// Signed delta in nanos per address touched by the transaction. The
+    /// sender's gas coin debit is not folded in here; see `gas_fee`.
+    pub deltas: BTreeMap<String, i64>,
+    /// Gas the sender paid for this transaction, in nanos.
+    pub gas_fee: u64,
+}
+
+/// A staked IOTA object owned by an address, as returned by
+/// [`NetworkClient::get_stakes`].
+#[derive(Debug, Clone)]
+pub struct StakedIotaSummary {
+    pub object_id: ObjectId,
+    pub pool_id: ObjectId,
+    pub principal: u64,
+    pub stake_activation_epoch: u64,
+    pub estimated_reward: Option<u64>,
+    pub status: StakeStatus,
+}
+
+/// A coin type held by an address, as returned by
+/// [`NetworkClient::get_token_balances`].
+#[derive(Debug, Clone)]
+pub struct TokenBalance {
+    /// Fully-qualified coin type, e.g. `0x2::iota::IOTA`.
+    pub coin_type: String,
+    /// Ticker symbol, if the node has metadata registered for this coin type.
+    pub symbol: Option<String>,
+    /// Decimal places this coin type uses; `amount` is in its base units.
+    pub decimals: u8,
+    /// Raw base-unit amount held.
+    pub amount: u64,
+}
+
+/// Decimals and symbol for a coin type, as reported by the node. Defaults to
+/// zero decimals and no symbol when the node has no metadata for it.
+#[derive(Debug, Clone, Default)]
+struct CoinMetadata {
+    decimals: u8,
+    symbol: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StakeStatus {
+    Pending,
+    Active,
+    Unstaked,
+}
+
+impl std::fmt::Display for StakeStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            StakeStatus::Pending => "Pending",
+            StakeStatus::Active => "Active",
+            StakeStatus::Unstaked => "Unstaked",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Extract a string field from a JSON value and parse it via `FromStr`.
+fn json_str_field<T: std::str::FromStr>(node: &serde_json::Value, key: &str) -> Option<T> {
+    node.get(key)
+        .and_then(|v| v.as_str())
+        .and_then(|s| s.parse().ok())
+}
+
+/// Extract a hex-encoded ObjectId from a JSON value.
+fn json_object_id(node: &serde_json::Value, key: &str) -> Option<ObjectId> {
+    node.get(key)
+        .and_then(|v| v.as_str())
+        .and_then(|s| ObjectId::from_hex(s).ok())
+}
+
+/// Pull a coin's balance and owning address out of one side (`inputState` or
+/// `outputState`) of an `objectChanges` node, if it's a coin at all. Most
+/// Move objects (staked IOTA, packages, ...) have no `balance` field in
+/// their contents and are filtered out by returning `None`.
+fn coin_balance_and_owner(state: Option<&serde_json::Value>) -> Option<(u64, String)> {
+    let state = state?;
+    let balance = state
+        .get("asMoveObject")
+        .and_then(|o| o.get("contents"))
+        .and_then(|c| c.get("json"))
+        .and_then(|j| j.get("balance"))
+        .and_then(|v| v.as_str())
+        .and_then(|s| s.parse::<u64>().ok())?;
+    let owner = state
+        .get("owner")
+        .and_then(|o| o.get("asAddressOwner"))
+        .and_then(|a| a.get("owner"))
+        .and_then(|o| o.get("address"))
+        .and_then(|v| v.as_str())
+        .map(str::to_string)?;
+    Some((balance, owner))
+}
+
+/// Result of estimating a transaction before signing.
+///
+/// Computed by [`NetworkClient::estimate_transfer`] via a dry run, so the
+/// caller can tell whether the sender's balance covers the amount plus gas
+/// before ever building a signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PreflightResult {
+    /// Amount plus gas budget, in nanos.
+    pub total_required: IotaAmount,
+    /// Gas the dry run reports the transaction would consume.
+    pub gas_budget: IotaAmount,
+    /// How much more the sender needs, if `total_required` exceeds their balance.
+    pub shortfall: Option<IotaAmount>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum TransactionFilter {
     All,
@@ -238,8 +1326,8 @@ pub struct TransactionSummary {
     pub timestamp: Option<String>,
     /// Sender address, if available from the SDK.
     pub sender: Option<String>,
-    /// Amount in nanos, if available from the SDK.
-    pub amount: Option<u64>,
+    /// Amount, if available from the SDK.
+    pub amount: Option<IotaAmount>,
 }
 
 #[cfg(test)]
@@ -251,6 +1339,8 @@ mod tests {
         let config = NetworkConfig {
             network: Network::Custom,
             custom_url: None,
+            retry: RetryConfig::default(),
+            quorum: None,
         };
 
         let result = NetworkClient::new(&config);
@@ -261,4 +1351,125 @@ mod tests {
             "error should mention missing URL, got: {err}"
         );
     }
+
+    #[test]
+    fn rate_limit_errors_are_retryable() {
+        let err = anyhow::anyhow!("429 Too Many Requests");
+        assert!(is_retryable(&err));
+        assert!(is_rate_limited(&err));
+    }
+
+    #[test]
+    fn connection_errors_are_retryable_but_not_rate_limited() {
+        let err = anyhow::anyhow!("connection reset by peer");
+        assert!(is_retryable(&err));
+        assert!(!is_rate_limited(&err));
+    }
+
+    #[test]
+    fn transaction_would_fail_is_fatal() {
+        let err = anyhow::anyhow!("Transaction would fail: insufficient gas");
+        assert!(!is_retryable(&err));
+    }
+
+    #[test]
+    fn backoff_delay_is_capped() {
+        let retry = RetryConfig {
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(1),
+            max_retries: 5,
+        };
+        // Even at a high attempt count, the cap plus jitter shouldn't run away.
+        let delay = backoff_delay(&retry, 20, false);
+        assert!(delay <= retry.max_delay.mul_f64(1.25));
+    }
+
+    #[test]
+    fn backoff_delay_grows_with_attempt() {
+        let retry = RetryConfig {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(60),
+            max_retries: 5,
+        };
+        let first = backoff_delay(&retry, 0, false);
+        let second = backoff_delay(&retry, 1, false);
+        // Jitter means these aren't exact multiples, but doubling the base
+        // should still outpace a single attempt's worth of jitter.
+        assert!(second > first);
+    }
+
+    #[test]
+    fn quorum_agree_returns_majority_value() {
+        let results = vec![1u64, 1, 2];
+        assert_eq!(quorum_agree(&results, 2), Some(1));
+    }
+
+    #[test]
+    fn quorum_agree_fails_below_threshold() {
+        let results = vec![1u64, 2, 3];
+        assert_eq!(quorum_agree(&results, 2), None);
+    }
+
+    #[test]
+    fn quorum_agree_empty_results_never_agree() {
+        let results: Vec<u64> = vec![];
+        assert_eq!(quorum_agree(&results, 1), None);
+    }
+
+    #[test]
+    fn coin_balance_and_owner_reads_move_object_state() {
+        let state = serde_json::json!({
+            "asMoveObject": { "contents": { "json": { "balance": "42" } } },
+            "owner": { "asAddressOwner": { "owner": { "address": "0xabc" } } },
+        });
+        assert_eq!(
+            coin_balance_and_owner(Some(&state)),
+            Some((42, "0xabc".to_string()))
+        );
+    }
+
+    #[test]
+    fn coin_balance_and_owner_skips_non_coin_objects() {
+        // A staked IOTA or package object has no `balance` field.
+        let state = serde_json::json!({
+            "asMoveObject": { "contents": { "json": { "poolId": "0x1" } } },
+            "owner": { "asAddressOwner": { "owner": { "address": "0xabc" } } },
+        });
+        assert_eq!(coin_balance_and_owner(Some(&state)), None);
+    }
+
+    #[test]
+    fn coin_balance_and_owner_handles_absent_state() {
+        // A deleted object has no `outputState`; a created one has no
+        // `inputState`.
+        assert_eq!(coin_balance_and_owner(None), None);
+    }
+
+    #[test]
+    fn single_recipient_picks_the_sole_non_sender_credit() {
+        let mut deltas = BTreeMap::new();
+        deltas.insert("0xsender".to_string(), -150i64);
+        deltas.insert("0xrecipient".to_string(), 100i64);
+        let changes = TransactionBalanceChanges { deltas, gas_fee: 50 };
+
+        let (recipient, amount) = single_recipient(&changes, "0xsender");
+        assert_eq!(recipient, Some("0xrecipient".to_string()));
+        assert_eq!(amount, Some(IotaAmount::from_nanos(100)));
+    }
+
+    #[test]
+    fn single_recipient_is_none_for_multiple_recipients() {
+        let mut deltas = BTreeMap::new();
+        deltas.insert("0xa".to_string(), 60i64);
+        deltas.insert("0xb".to_string(), 40i64);
+        let changes = TransactionBalanceChanges { deltas, gas_fee: 10 };
+
+        assert_eq!(single_recipient(&changes, "0xsender"), (None, None));
+    }
+
+    #[test]
+    fn single_recipient_is_none_for_pure_move_calls() {
+        let changes = TransactionBalanceChanges::default();
+        assert_eq!(single_recipient(&changes, "0xsender"), (None, None));
+    }
 }
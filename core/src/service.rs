@@ -4,8 +4,8 @@ use anyhow::Result;
 use iota_sdk::types::{Address, Digest, ObjectId};
 
 use crate::network::{
-    NetworkClient, NetworkStatus, StakedIotaSummary, TokenBalance, TransactionDetailsSummary,
-    TransferResult,
+    ConfirmationConfig, NetworkClient, NetworkStatus, StakedIotaSummary, TokenBalance,
+    TransactionDetailsSummary, TransferResult,
 };
 use crate::signer::Signer;
 
@@ -50,6 +50,27 @@ impl WalletService {
             .await
     }
 
+    /// Send IOTA and block until `config.confirmations` checkpoints have
+    /// landed on top of it. Unlike [`Self::send`], the caller gets the
+    /// finalized transaction details rather than the best-effort result
+    /// from execution.
+    pub async fn send_and_wait(
+        &self,
+        recipient: Address,
+        amount: u64,
+        config: &ConfirmationConfig,
+    ) -> Result<TransactionDetailsSummary> {
+        self.network
+            .send_iota_and_wait(
+                self.signer.as_ref(),
+                self.signer.address(),
+                recipient,
+                amount,
+                config,
+            )
+            .await
+    }
+
     pub async fn sweep_all(&self, recipient: Address) -> Result<(TransferResult, u64)> {
         self.network
             .sweep_all(self.signer.as_ref(), self.signer.address(), recipient)
@@ -62,6 +83,24 @@ impl WalletService {
             .await
     }
 
+    /// Stake IOTA and block until confirmed. See [`Self::send_and_wait`].
+    pub async fn stake_and_wait(
+        &self,
+        validator: Address,
+        amount: u64,
+        config: &ConfirmationConfig,
+    ) -> Result<TransactionDetailsSummary> {
+        self.network
+            .stake_iota_and_wait(
+                self.signer.as_ref(),
+                self.signer.address(),
+                validator,
+                amount,
+                config,
+            )
+            .await
+    }
+
     pub async fn unstake(&self, staked_object_id: ObjectId) -> Result<TransferResult> {
         self.network
             .unstake_iota(self.signer.as_ref(), self.signer.address(), staked_object_id)
@@ -40,3 +40,109 @@ impl Signer for SoftwareSigner {
         &self.address
     }
 }
+
+/// Ledger hardware-wallet signer, behind the `ledger` feature since it pulls
+/// in the HID transport and isn't needed by headless/CLI builds.
+#[cfg(feature = "ledger")]
+pub mod ledger {
+    use super::{Address, Result, Signer, Transaction, UserSignature};
+    use ledger_transport_hid::{hidapi::HidApi, TransportNativeHID};
+    use ledger_transport::APDUCommand;
+
+    /// A Ledger device enumerated over USB HID, before a connection is opened.
+    #[derive(Debug, Clone)]
+    pub struct LedgerDeviceInfo {
+        /// HID path used to open a transport to this specific device.
+        pub path: String,
+        /// Product name reported by the device, e.g. "Nano X".
+        pub model: String,
+    }
+
+    /// Enumerate connected Ledger devices without opening a connection to any of them.
+    pub fn discover_devices() -> Result<Vec<LedgerDeviceInfo>> {
+        let api = HidApi::new().map_err(|e| anyhow::anyhow!("Failed to access USB HID: {e}"))?;
+        Ok(TransportNativeHID::list_ledgers(&api)
+            .map(|info| LedgerDeviceInfo {
+                path: info.path().to_string_lossy().to_string(),
+                model: info.product_string().unwrap_or("Ledger").to_string(),
+            })
+            .collect())
+    }
+
+    const CLA_IOTA: u8 = 0xe0;
+    const INS_GET_PUBLIC_KEY: u8 = 0x02;
+    const INS_SIGN_TRANSACTION: u8 = 0x03;
+
+    /// Signer backed by a connected Ledger device.
+    ///
+    /// `address()` is fixed at connection time from the device's Ed25519
+    /// public key; `sign_transaction` round-trips through on-device signing,
+    /// so the user must confirm each transaction on the device's screen.
+    pub struct LedgerSigner {
+        transport: TransportNativeHID,
+        address: Address,
+    }
+
+    impl LedgerSigner {
+        /// Open a connection to `device` and derive its address from the
+        /// public key it reports. Fails if the device is locked, the IOTA
+        /// app isn't open, or it was disconnected after enumeration.
+        pub fn connect(device: &LedgerDeviceInfo) -> Result<Self> {
+            let api = HidApi::new().map_err(|e| anyhow::anyhow!("Failed to access USB HID: {e}"))?;
+            let transport = TransportNativeHID::open_path(&api, &device.path)
+                .map_err(|e| anyhow::anyhow!("Failed to connect to {}: {e}", device.model))?;
+
+            let response = transport
+                .exchange(&APDUCommand {
+                    cla: CLA_IOTA,
+                    ins: INS_GET_PUBLIC_KEY,
+                    p1: 0,
+                    p2: 0,
+                    data: Vec::new(),
+                })
+                .map_err(|e| map_device_error(&device.model, e))?;
+
+            let public_key = response.data();
+            let address = Address::from_ed25519_public_key_bytes(public_key)
+                .map_err(|e| anyhow::anyhow!("Device returned an invalid public key: {e}"))?;
+
+            Ok(Self { transport, address })
+        }
+    }
+
+    impl Signer for LedgerSigner {
+        fn sign_transaction(&self, tx: &Transaction) -> Result<UserSignature> {
+            let data = tx.to_bytes();
+            let response = self
+                .transport
+                .exchange(&APDUCommand {
+                    cla: CLA_IOTA,
+                    ins: INS_SIGN_TRANSACTION,
+                    p1: 0,
+                    p2: 0,
+                    data,
+                })
+                .map_err(|e| map_device_error("Ledger", e))?;
+
+            UserSignature::from_bytes(response.data())
+                .map_err(|e| anyhow::anyhow!("Device returned an invalid signature: {e}"))
+        }
+
+        fn address(&self) -> &Address {
+            &self.address
+        }
+    }
+
+    /// Translate a transport-level failure into a message that tells the
+    /// user what to actually do (unlock, reconnect, open the app).
+    fn map_device_error(model: &str, err: impl std::fmt::Display) -> anyhow::Error {
+        let msg = err.to_string();
+        if msg.contains("0x5515") || msg.contains("locked") {
+            anyhow::anyhow!("{model} is locked. Unlock it and open the IOTA app, then retry.")
+        } else if msg.contains("0x6e00") || msg.contains("not supported") {
+            anyhow::anyhow!("{model} doesn't have the IOTA app open. Open it and retry.")
+        } else {
+            anyhow::anyhow!("{model} disconnected or didn't respond: {msg}")
+        }
+    }
+}
@@ -0,0 +1,126 @@
+/// Background polling that keeps balance and transaction history fresh
+/// without depending on a node's MQTT broker, so the REPL's `sync on`
+/// command and the GUI's send view don't need the user to hit refresh.
+///
+/// Unlike [`crate::live`]'s MQTT-first stream, this is a plain interval
+/// poll: simpler, and good enough for a lightweight background refresh.
+/// Each tick is jittered by up to ±20% so many wallets polling the same
+/// node don't all land on the same second.
+use std::time::Duration;
+
+use rand::Rng;
+use tokio::sync::{oneshot, watch};
+use tokio::time::sleep;
+
+use iota_sdk::types::Address;
+
+use crate::display::IotaAmount;
+use crate::network::{NetworkClient, TransactionFilter, TransactionSummary};
+
+/// The latest coalesced state a sync task has observed.
+#[derive(Debug, Clone, Default)]
+pub struct SyncSnapshot {
+    /// `None` until the first successful poll.
+    pub balance: Option<IotaAmount>,
+    /// Recent transactions as of the last successful poll.
+    pub transactions: Vec<TransactionSummary>,
+    /// Set when the most recent poll failed; the previous fields keep
+    /// whatever they last held.
+    pub last_error: Option<String>,
+}
+
+/// A running background sync task plus the channel it publishes to.
+///
+/// Dropping the handle stops the task (the `oneshot::Sender` drops with
+/// it), same as `stop` but implicit.
+pub struct SyncHandle {
+    status: watch::Receiver<SyncSnapshot>,
+    stop: Option<oneshot::Sender<()>>,
+}
+
+impl SyncHandle {
+    /// Start polling `address` on `network` every `interval` (jittered),
+    /// publishing a fresh [`SyncSnapshot`] after each round.
+    #[must_use]
+    pub fn start(network: NetworkClient, address: Address, interval: Duration) -> Self {
+        let (status_tx, status_rx) = watch::channel(SyncSnapshot::default());
+        let (stop_tx, mut stop_rx) = oneshot::channel();
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = &mut stop_rx => return,
+                    _ = sleep(jittered(interval)) => {}
+                }
+
+                let previous = status_tx.borrow().clone();
+                let snapshot = poll_once(&network, &address, &previous).await;
+                if status_tx.send(snapshot).is_err() {
+                    return;
+                }
+            }
+        });
+
+        Self {
+            status: status_rx,
+            stop: Some(stop_tx),
+        }
+    }
+
+    /// A receiver that yields the latest snapshot whenever a new one is
+    /// published. Clone freely; the REPL and GUI can each hold their own.
+    #[must_use]
+    pub fn subscribe(&self) -> watch::Receiver<SyncSnapshot> {
+        self.status.clone()
+    }
+
+    /// The most recently published snapshot, without waiting for a new one.
+    #[must_use]
+    pub fn latest(&self) -> SyncSnapshot {
+        self.status.borrow().clone()
+    }
+
+    /// Stop the background task.
+    pub fn stop(mut self) {
+        if let Some(stop) = self.stop.take() {
+            let _ = stop.send(());
+        }
+    }
+}
+
+/// Fetch balance and the freshest transaction history, coalesced into one
+/// snapshot. Whatever fails to fetch keeps its value from `previous`.
+async fn poll_once(
+    network: &NetworkClient,
+    address: &Address,
+    previous: &SyncSnapshot,
+) -> SyncSnapshot {
+    let balance = match network.balance(address).await {
+        Ok(nanos) => IotaAmount::from_nanos(nanos),
+        Err(e) => {
+            return SyncSnapshot {
+                last_error: Some(e.to_string()),
+                ..previous.clone()
+            }
+        }
+    };
+
+    match network.transactions(address, TransactionFilter::All).await {
+        Ok(transactions) => SyncSnapshot {
+            balance: Some(balance),
+            transactions,
+            last_error: None,
+        },
+        Err(e) => SyncSnapshot {
+            balance: Some(balance),
+            transactions: previous.transactions.clone(),
+            last_error: Some(e.to_string()),
+        },
+    }
+}
+
+/// Jitter `interval` by up to ±20%.
+fn jittered(interval: Duration) -> Duration {
+    let factor = rand::thread_rng().gen_range(0.8..1.2);
+    interval.mul_f64(factor)
+}
@@ -0,0 +1,126 @@
+/// At-rest encryption for the wallet's seed material.
+///
+/// A password is run through argon2id (memory-hard, so brute-forcing a
+/// stolen file is expensive) to derive a symmetric key, which seals the
+/// mnemonic/private key with XChaCha20-Poly1305. Salt and nonce are stored
+/// alongside the ciphertext since neither needs to be secret, only unique.
+use anyhow::{bail, Result};
+use argon2::Argon2;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use rand::RngCore;
+use zeroize::Zeroize;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const KEY_LEN: usize = 32;
+
+/// A password-sealed secret, ready to persist alongside the wallet file.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EncryptedSeed {
+    salt: [u8; SALT_LEN],
+    nonce: [u8; NONCE_LEN],
+    ciphertext: Vec<u8>,
+}
+
+impl EncryptedSeed {
+    /// Derive a key from `password` via a fresh random salt, and seal
+    /// `plaintext` under it with a fresh random nonce. `plaintext` is
+    /// zeroized before returning, whether or not sealing succeeded.
+    pub fn seal(password: &str, mut plaintext: Vec<u8>) -> Result<Self> {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce_bytes);
+        let nonce = XNonce::from_slice(&nonce_bytes);
+
+        let result = derive_key(password, &salt).and_then(|mut key| {
+            let sealed = XChaCha20Poly1305::new_from_slice(&key)
+                .map_err(|e| anyhow::anyhow!("Invalid key length: {e}"))
+                .and_then(|cipher| {
+                    cipher
+                        .encrypt(nonce, plaintext.as_slice())
+                        .map_err(|e| anyhow::anyhow!("Encryption failed: {e}"))
+                });
+            key.zeroize();
+            sealed
+        });
+
+        plaintext.zeroize();
+
+        Ok(Self {
+            salt,
+            nonce: nonce_bytes,
+            ciphertext: result?,
+        })
+    }
+
+    /// Re-derive the key from `password` and open the ciphertext. Fails
+    /// with an opaque error on a wrong password (AEAD doesn't distinguish
+    /// "wrong key" from "tampered ciphertext").
+    pub fn open(&self, password: &str) -> Result<Vec<u8>> {
+        let mut key = derive_key(password, &self.salt)?;
+        let nonce = XNonce::from_slice(&self.nonce);
+
+        let cipher = XChaCha20Poly1305::new_from_slice(&key)
+            .map_err(|e| anyhow::anyhow!("Invalid key length: {e}"))?;
+        let plaintext = cipher
+            .decrypt(nonce, self.ciphertext.as_slice())
+            .map_err(|_| anyhow::anyhow!("Wrong password or corrupted wallet file"));
+
+        key.zeroize();
+        plaintext
+    }
+}
+
+/// Derive a 32-byte key from `password` and `salt` via argon2id with the
+/// crate's default (memory-hard) parameters.
+fn derive_key(password: &str, salt: &[u8; SALT_LEN]) -> Result<[u8; KEY_LEN]> {
+    let mut key = [0u8; KEY_LEN];
+    Argon2::default()
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow::anyhow!("Key derivation failed: {e}"))?;
+    Ok(key)
+}
+
+/// Validate a password isn't trivially empty before spending time on argon2.
+pub fn require_nonempty_password(password: &str) -> Result<()> {
+    if password.is_empty() {
+        bail!("Password cannot be empty.");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seal_and_open_roundtrip() {
+        let sealed = EncryptedSeed::seal("hunter2", b"super secret mnemonic".to_vec()).unwrap();
+        let opened = sealed.open("hunter2").unwrap();
+        assert_eq!(opened, b"super secret mnemonic");
+    }
+
+    #[test]
+    fn wrong_password_fails() {
+        let sealed = EncryptedSeed::seal("hunter2", b"super secret mnemonic".to_vec()).unwrap();
+        assert!(sealed.open("wrong password").is_err());
+    }
+
+    #[test]
+    fn salt_and_nonce_are_random_per_seal() {
+        let a = EncryptedSeed::seal("hunter2", b"same plaintext".to_vec()).unwrap();
+        let b = EncryptedSeed::seal("hunter2", b"same plaintext".to_vec()).unwrap();
+        assert_ne!(a.salt, b.salt);
+        assert_ne!(a.nonce, b.nonce);
+        assert_ne!(a.ciphertext, b.ciphertext);
+    }
+
+    #[test]
+    fn empty_password_rejected() {
+        assert!(require_nonempty_password("").is_err());
+        assert!(require_nonempty_password("x").is_ok());
+    }
+}
@@ -1,7 +1,8 @@
+use crate::live::LiveStatus;
 use crate::messages::Message;
 use crate::state::Screen;
 use crate::{styles, App, MUTED};
-use iced::widget::{button, canvas, column, container, row, text, Space};
+use iced::widget::{button, canvas, column, container, row, text, text_input, Space};
 use iced::{Element, Fill, Length};
 use iota_wallet_core::wallet::Network;
 
@@ -13,13 +14,23 @@ impl App {
 
         let title = text("Account").size(24);
 
+        // Live updates replace manual refresh once the MQTT (or polling
+        // fallback) stream is up; "Refresh" stays as a manual override.
+        let live_indicator = match self.live_status {
+            LiveStatus::Live => text("● Live").size(12).color(styles::ACCENT),
+            LiveStatus::Reconnecting => text("● Reconnecting…").size(12).color(MUTED),
+            LiveStatus::Polling => text("● Polling").size(12).color(MUTED),
+        };
+
         let mut actions = row![
+            live_indicator,
             button(text("Refresh").size(13))
                 .padding([8, 16])
                 .style(styles::btn_secondary)
                 .on_press(Message::RefreshBalance),
         ]
-        .spacing(8);
+        .spacing(8)
+        .align_y(iced::Alignment::Center);
 
         if !info.is_mainnet && info.network_config.network != Network::Custom {
             let mut faucet = button(text("Faucet").size(13))
@@ -80,6 +91,11 @@ impl App {
             let count = self.account_transactions.len().min(5);
             tx_content =
                 tx_content.push(self.view_tx_table(&self.account_transactions[..count], false));
+
+            for tx in &self.account_transactions[..count] {
+                tx_content = tx_content.push(self.view_tx_label_row(&tx.digest));
+            }
+
             if self.account_transactions.len() > 5 {
                 tx_content = tx_content.push(
                     button(text("View all transactions →").size(12))
@@ -98,4 +114,36 @@ impl App {
 
         col.into()
     }
+
+    /// A compact "label: [edit] [save]" row shown beneath a transaction,
+    /// letting the user attach a local label to its digest.
+    fn view_tx_label_row(&self, digest: &str) -> Element<Message> {
+        let saved_label = self.labels.get(digest);
+        let draft = self.label_drafts.get(digest);
+
+        match (saved_label, draft) {
+            (_, Some(draft)) => row![
+                text_input("Label this transaction", draft)
+                    .size(12)
+                    .on_input(move |s| Message::LabelDraftChanged(digest.to_string(), s)),
+                button(text("Save").size(11))
+                    .style(styles::btn_secondary)
+                    .on_press(Message::LabelUpdated(digest.to_string(), draft.clone())),
+            ]
+            .spacing(6)
+            .into(),
+            (Some(label), None) => row![
+                text(format!("🏷 {label}")).size(11).color(MUTED),
+                button(text("Edit").size(11))
+                    .style(styles::btn_ghost)
+                    .on_press(Message::LabelDraftChanged(digest.to_string(), label.to_string())),
+            ]
+            .spacing(6)
+            .into(),
+            (None, None) => button(text("+ Add label").size(11))
+                .style(styles::btn_ghost)
+                .on_press(Message::LabelDraftChanged(digest.to_string(), String::new()))
+                .into(),
+        }
+    }
 }
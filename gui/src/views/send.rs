@@ -1,8 +1,10 @@
 use crate::messages::Message;
+use crate::sync::SyncStatus;
 use crate::{styles, App, MUTED};
 use iced::widget::{button, column, container, row, text, text_input, Space};
 use iced::{Element, Fill};
 use iota_wallet_core::display::format_balance;
+use iota_wallet_core::network::PreflightResult;
 
 impl App {
     pub(crate) fn view_send(&self) -> Element<Message> {
@@ -17,6 +19,15 @@ impl App {
             None => "Balance: loading...".into(),
         };
 
+        // Background sync keeps `self.balance` fresh between keystrokes;
+        // this just tells the user whether that's currently happening.
+        let sync_indicator = match self.sync_status {
+            SyncStatus::Off => None,
+            SyncStatus::Syncing => Some(text("● Syncing").size(11).color(MUTED)),
+            SyncStatus::Synced => Some(text("● Synced").size(11).color(styles::ACCENT)),
+            SyncStatus::Error => Some(text("● Sync error").size(11).color(styles::DANGER)),
+        };
+
         let recipient = text_input("Recipient address or .iota name", &self.recipient)
             .on_input(Message::RecipientChanged);
 
@@ -38,29 +49,60 @@ impl App {
             .on_input(Message::AmountChanged)
             .on_submit(Message::ConfirmSend);
 
+        // Preflight estimate of amount + gas against the current balance,
+        // refreshed whenever the recipient or amount changes (see
+        // Message::AmountChanged / Message::RecipientChanged).
+        let shortfall = match &self.preflight {
+            Some(Ok(PreflightResult {
+                shortfall: Some(short),
+                ..
+            })) => Some(*short),
+            _ => None,
+        };
+        let preflight_hint: Option<Element<Message>> = match &self.preflight {
+            Some(Ok(PreflightResult {
+                shortfall: Some(short),
+                ..
+            })) => Some(
+                text(format!("Need {short} more IOTA to cover amount + gas"))
+                    .size(11)
+                    .color(styles::DANGER)
+                    .into(),
+            ),
+            Some(Err(e)) => Some(text(e.as_str()).size(11).color(styles::DANGER).into()),
+            _ => None,
+        };
+
         let mut send = button(text("Send").size(14))
             .padding([10, 24])
             .style(styles::btn_primary);
-        if self.loading == 0 && !self.recipient.is_empty() && !self.amount.is_empty() {
+        if self.loading == 0
+            && !self.recipient.is_empty()
+            && !self.amount.is_empty()
+            && shortfall.is_none()
+        {
             send = send.on_press(Message::ConfirmSend);
         }
 
-        let mut form = column![
-            text(bal_label).size(14).font(styles::BOLD),
-            Space::new().height(8),
-            text("Recipient").size(12).color(MUTED),
-            recipient,
-        ]
-        .spacing(4);
+        let mut form = column![text(bal_label).size(14).font(styles::BOLD)].spacing(4);
+        if let Some(indicator) = sync_indicator {
+            form = form.push(indicator);
+        }
+        form = form
+            .push(Space::new().height(8))
+            .push(text("Recipient").size(12).color(MUTED))
+            .push(recipient);
         if let Some(hint) = resolved_hint {
             form = form.push(hint);
         }
         form = form
             .push(Space::new().height(4))
             .push(text("Amount").size(12).color(MUTED))
-            .push(amount)
-            .push(Space::new().height(12))
-            .push(send);
+            .push(amount);
+        if let Some(hint) = preflight_hint {
+            form = form.push(hint);
+        }
+        form = form.push(Space::new().height(12)).push(send);
 
         let header = row![title, Space::new().width(Fill)]
             .align_y(iced::Alignment::Center);
@@ -65,6 +65,41 @@ impl App {
             .spacing(10),
         );
 
+        #[cfg(feature = "ledger")]
+        {
+            col = col.push(Space::new().height(12));
+            col = col.push(self.view_ledger_devices());
+        }
+
         col.into()
     }
+
+    /// List Ledger devices found by [`Message::RefreshLedgerDevices`],
+    /// letting the user pick one to sign with instead of a software wallet.
+    #[cfg(feature = "ledger")]
+    fn view_ledger_devices(&self) -> Element<Message> {
+        let mut section = column![text("Hardware wallet:").size(14).color(MUTED)].spacing(6);
+
+        if self.ledger_devices.is_empty() {
+            section = section.push(
+                button(text("Scan for Ledger").size(13))
+                    .padding([8, 16])
+                    .style(styles::btn_secondary)
+                    .on_press(Message::RefreshLedgerDevices),
+            );
+        } else {
+            for device in &self.ledger_devices {
+                let path = device.path.clone();
+                section = section.push(
+                    button(text(device.model.as_str()).size(14))
+                        .on_press(Message::HardwareWalletSelected(path))
+                        .padding([10, 16])
+                        .style(styles::btn_secondary)
+                        .width(Fill),
+                );
+            }
+        }
+
+        section.into()
+    }
 }